@@ -1,7 +1,7 @@
 import { useState } from 'react';
 import { invoke } from '@tauri-apps/api/core';
 
-export default function ProjectCard({ project, tagColors, onOpenTagEditor }) {
+export default function ProjectCard({ project, tagColors, onOpenTagEditor, onOpenReadme }) {
   const [showProgressMenu, setShowProgressMenu] = useState(false);
   const [isUpdatingProgress, setIsUpdatingProgress] = useState(false);
 
@@ -21,7 +21,7 @@ export default function ProjectCard({ project, tagColors, onOpenTagEditor }) {
       };
 
       const data = await invoke('save_tags', {
-        projectName: project.name,
+        projectPath: project.path,
         tags: updatedTags
       });
 
@@ -60,6 +60,7 @@ export default function ProjectCard({ project, tagColors, onOpenTagEditor }) {
   }
 
   function formatDate(dateString) {
+    if (!dateString) return '알 수 없음';
     const date = new Date(dateString);
     const now = new Date();
     const diffMs = now - date;
@@ -84,6 +85,26 @@ export default function ProjectCard({ project, tagColors, onOpenTagEditor }) {
   }
 
   const { tags } = project;
+  const gitStatus = project.gitStatus;
+  const remoteHost = (project.gitRemotes || []).find(r => r.webUrl === project.gitRemote)?.hostKind;
+  const remoteLabel = {
+    github: 'GitHub',
+    gitlab: 'GitLab',
+    bitbucket: 'Bitbucket',
+    gitea: 'Gitea',
+  }[remoteHost] || '저장소';
+  const uncommitted = gitStatus
+    ? gitStatus.staged + gitStatus.unstaged + gitStatus.untracked + gitStatus.conflicted
+    : 0;
+  const release = project.release;
+  const version = release?.declaredVersions?.[0]?.version;
+  const releaseTitle = [
+    ...(release?.declaredVersions || []).map(v => `${v.source}: ${v.version}`),
+    release?.latestTag && `태그: ${release.latestTag} (+${release.commitsSinceTag ?? 0})`,
+    release?.changelog?.latest && `${release.changelog.file}: ${release.changelog.latest.heading}`,
+  ].filter(Boolean).join('\n');
+  const diagnostics = project.diagnostics || [];
+  const hasErrors = diagnostics.some(d => d.severity === 'error');
   const displayTitle = tags?.customTitle || project.name;
   const showFolderName = tags?.customTitle && tags.customTitle !== project.name;
 
@@ -105,6 +126,17 @@ export default function ProjectCard({ project, tagColors, onOpenTagEditor }) {
               📁 {project.name}
             </p>
           )}
+          {(project.rootLabel || project.group) && (
+            <p className="text-xs text-gray-400 mt-1 truncate" title={project.path}>
+              📂 {[project.rootLabel, project.group].filter(Boolean).join(' / ')}
+            </p>
+          )}
+          {project.aliases?.length > 0 && (
+            <p className="text-xs text-gray-400 mt-1 truncate" title={project.aliases.join('\n')}>
+              🔗 {project.aliases[0]}
+              {project.aliases.length > 1 && ` 외 ${project.aliases.length - 1}개`}
+            </p>
+          )}
         </div>
       </div>
 
@@ -182,9 +214,89 @@ export default function ProjectCard({ project, tagColors, onOpenTagEditor }) {
         </div>
       )}
 
+      {/* 스캔 진단 (깨진 매니페스트 등) */}
+      {diagnostics.length > 0 && (
+        <div
+          className={`mb-2 text-xs px-2 py-1 rounded ${hasErrors ? 'bg-red-50 text-red-700' : 'bg-yellow-50 text-yellow-800'}`}
+          title={diagnostics.map(d => `${d.file || '(폴더)'}: ${d.message}`).join('\n')}
+        >
+          ⚠ {project.partial && <span className="font-semibold">부분 스캔 · </span>}
+          {diagnostics[0].file || '폴더'}: {diagnostics[0].message}
+          {diagnostics.length > 1 && ` 외 ${diagnostics.length - 1}건`}
+        </div>
+      )}
+
+      {/* 버전 및 릴리스 */}
+      {(version || release?.latestTag || release?.changelog?.latest) && (
+        <div className="flex gap-1 flex-wrap items-center mb-2 text-xs" title={releaseTitle}>
+          <span className="font-mono text-gray-600">
+            🏷 {version || release.latestTag || release.changelog.latest.version || release.changelog.latest.heading}
+          </span>
+          {release.hasUnreleasedChanges && (
+            <span className="bg-purple-100 text-purple-800 px-2 py-0.5 rounded">
+              미배포{release.commitsSinceTag > 0 ? ` ${release.commitsSinceTag}커밋` : ''}
+            </span>
+          )}
+        </div>
+      )}
+
+      {/* Git 상태 */}
+      {gitStatus && (
+        <div className="flex gap-1 flex-wrap items-center mb-2 text-xs">
+          <span className="font-mono text-gray-600 truncate max-w-[140px]" title={gitStatus.upstream || ''}>
+            🌿 {gitStatus.detached ? `HEAD@${(gitStatus.headCommit || '').slice(0, 7)}` : gitStatus.branch}
+          </span>
+          {uncommitted > 0 && (
+            <span
+              className="bg-amber-100 text-amber-800 px-2 py-0.5 rounded"
+              title={`staged ${gitStatus.staged} · unstaged ${gitStatus.unstaged} · untracked ${gitStatus.untracked}`}
+            >
+              변경 {uncommitted}
+            </span>
+          )}
+          {gitStatus.ahead > 0 && (
+            <span className="bg-blue-100 text-blue-800 px-2 py-0.5 rounded" title="push하지 않은 커밋">
+              ↑{gitStatus.ahead}
+            </span>
+          )}
+          {gitStatus.behind > 0 && (
+            <span className="bg-gray-100 text-gray-700 px-2 py-0.5 rounded" title="가져오지 않은 커밋">
+              ↓{gitStatus.behind}
+            </span>
+          )}
+          {gitStatus.stashes > 0 && (
+            <span className="bg-gray-100 text-gray-700 px-2 py-0.5 rounded">
+              stash {gitStatus.stashes}
+            </span>
+          )}
+          {project.worktree && (
+            <span
+              className="bg-green-50 text-green-800 px-2 py-0.5 rounded"
+              title={[project.worktree.mainPath, ...project.worktree.linkedPaths].filter(Boolean).join('\n')}
+            >
+              {project.worktree.isLinked ? 'worktree' : `worktree ${project.worktree.linkedPaths.length}개`}
+            </span>
+          )}
+          {project.submodules?.length > 0 && (
+            <span
+              className="bg-gray-100 text-gray-700 px-2 py-0.5 rounded"
+              title={project.submodules.map(m => `${m.path} @ ${(m.commit || '?').slice(0, 7)}`).join('\n')}
+            >
+              서브모듈 {project.submodules.length}
+            </span>
+          )}
+        </div>
+      )}
+
       {/* 메타 정보 */}
       <div className="text-xs text-gray-500 mb-3">
         <div>수정: {formatDate(project.lastModified)}</div>
+        {project.lastCommit && (
+          <div className="mt-1">커밋: {formatDate(project.lastCommit)}</div>
+        )}
+        {project.lastOpened && (
+          <div className="mt-1">최근 열람: {formatDate(project.lastOpened)}</div>
+        )}
         {project.type && project.type.length > 0 && (
           <div className="mt-1">타입: {project.type.join(', ')}</div>
         )}
@@ -217,11 +329,18 @@ export default function ProjectCard({ project, tagColors, onOpenTagEditor }) {
           <button
             onClick={() => openProject('github')}
             className="flex-1 min-w-[80px] bg-gray-700 text-white px-2 py-1 rounded text-xs hover:bg-gray-800 transition-colors"
-            title="GitHub에서 보기"
+            title={`${remoteLabel}에서 보기`}
           >
-            🔗 GitHub
+            🔗 {remoteLabel}
           </button>
         )}
+        <button
+          onClick={onOpenReadme}
+          className="flex-1 min-w-[80px] bg-amber-500 text-white px-2 py-1 rounded text-xs hover:bg-amber-600 transition-colors"
+          title="README 보기"
+        >
+          📖 README
+        </button>
         <button
           onClick={onOpenTagEditor}
           className="flex-1 min-w-[80px] bg-green-500 text-white px-2 py-1 rounded text-xs hover:bg-green-600 transition-colors"
@@ -0,0 +1,106 @@
+import { useState, useEffect } from 'react';
+import { invoke } from '@tauri-apps/api/core';
+
+// 백엔드가 README의 상대 경로를 바꾼 asset URL 접두어 (Windows는 http://asset.localhost/)
+const ASSET_URL_PREFIXES = ['asset://localhost/', 'http://asset.localhost/'];
+
+export default function ReadmeViewer({ project, onClose }) {
+  const [readme, setReadme] = useState(null);
+  const [loading, setLoading] = useState(true);
+  const [error, setError] = useState(null);
+
+  useEffect(() => {
+    fetchReadme();
+  }, [project.path]);
+
+  async function fetchReadme() {
+    try {
+      const data = await invoke('get_project_readme', { projectPath: project.path });
+
+      if (data.success && data.readme) {
+        setReadme(data.readme);
+      } else {
+        setError(data.message || 'README를 불러올 수 없습니다.');
+      }
+    } catch (err) {
+      console.error('Error fetching project README:', err);
+      setError('README를 불러오는 중 오류가 발생했습니다.');
+    } finally {
+      setLoading(false);
+    }
+  }
+
+  // README 안의 링크는 앱 화면 대신 브라우저로 열고, 프로젝트 파일은 실행하지 않고 Finder에서 위치만 표시
+  async function handleContentClick(e) {
+    const link = e.target.closest('a[href]');
+    if (!link) return;
+
+    const href = link.getAttribute('href');
+    if (href.startsWith('#')) return;
+    e.preventDefault();
+
+    const assetPrefix = ASSET_URL_PREFIXES.find(prefix => href.startsWith(prefix));
+
+    try {
+      let result;
+      if (assetPrefix) {
+        const filePath = decodeURIComponent(href.slice(assetPrefix.length));
+        result = await invoke('reveal_project_file', { projectPath: project.path, filePath });
+      } else if (/^(https?:|mailto:)/i.test(href)) {
+        result = await invoke('open_url', { url: href });
+      } else {
+        return;
+      }
+
+      if (!result.success) {
+        console.error('Failed to open README link:', result.message);
+      }
+    } catch (err) {
+      console.error('Error opening README link:', err);
+    }
+  }
+
+  return (
+    <div className="fixed inset-0 bg-black bg-opacity-50 flex items-center justify-center p-4 z-50">
+      <div className="bg-white rounded-lg max-w-4xl w-full max-h-[90vh] overflow-y-auto">
+        {/* 헤더 */}
+        <div className="sticky top-0 bg-white border-b px-6 py-4">
+          <div className="flex items-center justify-between">
+            <h2 className="text-xl font-bold text-gray-900">{project.tags?.customTitle || project.name}</h2>
+            <button
+              onClick={onClose}
+              className="text-gray-400 hover:text-gray-600 text-2xl"
+            >
+              ×
+            </button>
+          </div>
+          {readme && (
+            <p className="text-sm text-gray-500 mt-1 truncate" title={readme.path}>
+              📖 {readme.file}
+            </p>
+          )}
+        </div>
+
+        <div className="px-6 py-6">
+          {loading && <div className="text-gray-500">README 로드 중...</div>}
+          {error && <div className="text-red-500">{error}</div>}
+
+          {readme?.html != null ? (
+            <article
+              onClick={handleContentClick}
+              className="prose prose-slate max-w-none
+                prose-a:text-blue-600 hover:prose-a:underline
+                prose-code:before:content-none prose-code:after:content-none
+                prose-pre:bg-gray-900 prose-pre:text-gray-100 prose-pre:overflow-x-auto
+                prose-img:inline prose-img:my-2
+                prose-table:text-sm"
+              dangerouslySetInnerHTML={{ __html: readme.html }}
+            />
+          ) : readme && (
+            <pre className="whitespace-pre-wrap text-sm text-gray-800 font-mono">{readme.content}</pre>
+          )}
+        </div>
+      </div>
+    </div>
+  );
+}
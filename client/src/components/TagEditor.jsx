@@ -69,7 +69,7 @@ export default function TagEditor({
       notes
     };
 
-    onSave(project.name, tags);
+    onSave(project.path, tags);
   }
 
   function getTagColor(tag, category) {
@@ -239,7 +239,7 @@ export default function TagEditor({
         <div className="sticky bottom-0 bg-gray-50 border-t px-6 py-4 flex justify-between">
           <Link
             to="/tags"
-            state={{ returnToProject: project.name }}
+            state={{ returnToProject: project.path }}
             className="px-4 py-2 text-gray-700 hover:bg-gray-200 rounded transition-colors"
           >
             ⚙️ 구분 태그 전체 관리
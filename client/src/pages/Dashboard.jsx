@@ -1,10 +1,12 @@
-import { useState, useEffect } from 'react';
+import { useState, useEffect, useRef } from 'react';
 import { useLocation } from 'react-router-dom';
-import { invoke } from '@tauri-apps/api/core';
+import { invoke, Channel } from '@tauri-apps/api/core';
+import { listen } from '@tauri-apps/api/event';
 import ProjectCard from '../components/ProjectCard';
 import SearchBar from '../components/SearchBar';
 import FilterPanel from '../components/FilterPanel';
 import TagEditor from '../components/TagEditor';
+import ReadmeViewer from '../components/ReadmeViewer';
 
 export default function Dashboard() {
   const location = useLocation();
@@ -19,10 +21,15 @@ export default function Dashboard() {
     favorite: false
   });
   const [tagEditorOpen, setTagEditorOpen] = useState(false);
+  const [readmeProject, setReadmeProject] = useState(null);
   const [selectedProject, setSelectedProject] = useState(null);
   const [tagDefinitions, setTagDefinitions] = useState(null);
   const [tagColors, setTagColors] = useState(null);
   const [settings, setSettings] = useState(null);
+  const [scanMetrics, setScanMetrics] = useState(null);
+  const [scanProgress, setScanProgress] = useState(null);
+  const [scanErrors, setScanErrors] = useState([]);
+  const activeScan = useRef(null);
 
   // 프로젝트 로드
   useEffect(() => {
@@ -31,6 +38,25 @@ export default function Dashboard() {
     fetchSettings();
   }, []);
 
+  // 스캔 루트 감시 이벤트 반영 (추가/변경/삭제된 프로젝트)
+  useEffect(() => {
+    const unlisteners = [
+      listen('project-added', event => {
+        setProjects(prev => [...prev.filter(p => p.path !== event.payload.path), event.payload]);
+      }),
+      listen('project-updated', event => {
+        setProjects(prev => prev.map(p => (p.path === event.payload.path ? event.payload : p)));
+      }),
+      listen('project-removed', event => {
+        setProjects(prev => prev.filter(p => p.path !== event.payload.path));
+      }),
+    ];
+
+    return () => {
+      unlisteners.forEach(promise => promise.then(unlisten => unlisten()));
+    };
+  }, []);
+
   async function fetchSettings() {
     try {
       const data = await invoke('get_settings');
@@ -42,12 +68,12 @@ export default function Dashboard() {
 
   // location.state 감지 및 모달 자동 재개방
   useEffect(() => {
-    const reopenProjectName = location.state?.reopenProject;
+    const reopenProjectPath = location.state?.reopenProject;
     const newTagAdded = location.state?.newTagAdded;
 
-    if (reopenProjectName && projects.length > 0) {
+    if (reopenProjectPath && projects.length > 0) {
       // 해당 프로젝트 찾기
-      const projectToReopen = projects.find(p => p.name === reopenProjectName);
+      const projectToReopen = projects.find(p => p.path === reopenProjectPath);
 
       if (projectToReopen) {
         // 태그 목록 새로고침
@@ -116,26 +142,73 @@ export default function Dashboard() {
       result = result.filter(p => p.tags.favorite);
     }
 
-    // 최종 수정일 기준 내림차순 정렬 (최신순)
+    // 최종 수정일 기준 내림차순 정렬 (최신순, 수정일을 모르는 프로젝트는 마지막)
     result.sort((a, b) => {
-      const dateA = new Date(a.lastModified);
-      const dateB = new Date(b.lastModified);
+      const dateA = a.lastModified ? new Date(a.lastModified) : 0;
+      const dateB = b.lastModified ? new Date(b.lastModified) : 0;
       return dateB - dateA;
     });
 
-    setFilteredProjects(result);
+    // 같은 저장소의 worktree는 가장 최근 항목 위치에 모아서 표시
+    const grouped = [];
+    const seenRepositories = new Set();
+    for (const project of result) {
+      const repository = project.worktree?.repository;
+      if (!repository) {
+        grouped.push(project);
+      } else if (!seenRepositories.has(repository)) {
+        seenRepositories.add(repository);
+        grouped.push(...result.filter(p => p.worktree?.repository === repository));
+      }
+    }
+
+    setFilteredProjects(grouped);
   }, [projects, searchQuery, filters, settings]);
 
-  async function fetchProjects() {
-    try {
-      const data = await invoke('get_projects');
-      if (data.success) {
-        setProjects(data.projects);
-        setFilteredProjects(data.projects);
+  // 스캔 시작 (진행 중인 이전 스캔은 백엔드에서 취소되고, 이전 채널의 이벤트는 무시)
+  async function fetchProjects(forceRescan = false) {
+    const onEvent = new Channel();
+    activeScan.current = onEvent;
+
+    onEvent.onmessage = ({ event, data }) => {
+      if (activeScan.current !== onEvent) return;
+
+      switch (event) {
+        case 'started':
+          setProjects([]);
+          setScanErrors([]);
+          setScanProgress({ total: data.total, scanned: 0 });
+          setLoading(false);
+          break;
+        case 'project':
+          setProjects(prev => [...prev, data.project]);
+          setScanProgress(prev => prev && { ...prev, scanned: prev.scanned + 1 });
+          break;
+        case 'finished':
+          // 폴더 이름 기준 태그를 경로 기준으로 옮긴 프로젝트는 새 태그로 교체
+          if (data.retagged.length > 0) {
+            setProjects(prev => prev.map(p => data.retagged.find(r => r.path === p.path) ?? p));
+          }
+          setScanMetrics(data.metrics);
+          setScanErrors(data.errors);
+          setScanProgress(null);
+          break;
+        case 'failed':
+          console.error('Error scanning projects:', data.message);
+          setProjects([]);
+          setScanErrors(data.message.split('\n'));
+          setScanProgress(null);
+          setLoading(false);
+          break;
+        default:
+          break;
       }
+    };
+
+    try {
+      await invoke('start_scan', { forceRescan, onEvent });
     } catch (error) {
       console.error('Error fetching projects:', error);
-    } finally {
       setLoading(false);
     }
   }
@@ -162,13 +235,13 @@ export default function Dashboard() {
     setSelectedProject(null);
   }
 
-  async function handleSaveTags(projectName, tags) {
+  async function handleSaveTags(projectPath, tags) {
     try {
-      const data = await invoke('save_tags', { projectName, tags });
+      const data = await invoke('save_tags', { projectPath, tags });
       if (data.success) {
         // 프로젝트 목록 업데이트
         setProjects(prev => prev.map(p =>
-          p.name === projectName ? { ...p, tags } : p
+          p.path === projectPath ? { ...p, tags } : p
         ));
         handleCloseTagEditor();
       } else {
@@ -181,14 +254,8 @@ export default function Dashboard() {
   }
 
   async function handleRescan() {
-    setLoading(true);
-    try {
-      await fetchProjects();
-    } catch (error) {
-      console.error('Error rescanning projects:', error);
-    } finally {
-      setLoading(false);
-    }
+    // 캐시를 무시하고 전체 다시 스캔
+    await fetchProjects(true);
   }
 
   if (loading) {
@@ -222,6 +289,16 @@ export default function Dashboard() {
       </div>
 
       <div className="max-w-7xl mx-auto px-4 py-6 sm:px-6 lg:px-8">
+        {/* 스캔 오류 (없는 스캔 경로, 잘못된 감지 규칙 파일 등) */}
+        {scanErrors.length > 0 && (
+          <div className="mb-4 p-3 bg-red-50 border border-red-200 rounded-lg text-sm text-red-800">
+            <div className="font-semibold mb-1">스캔 중 문제가 발생했습니다</div>
+            {scanErrors.map(error => (
+              <div key={error} className="font-mono text-xs">{error}</div>
+            ))}
+          </div>
+        )}
+
         <div className="flex gap-6">
           {/* 필터 패널 */}
           <aside className="w-64 flex-shrink-0">
@@ -236,15 +313,33 @@ export default function Dashboard() {
           <main className="flex-1">
             <div className="mb-4 text-sm text-gray-600">
               총 {filteredProjects.length}개 프로젝트
+              {scanProgress && (
+                <span className="ml-2 text-blue-500">
+                  · 스캔 중 {scanProgress.scanned}/{scanProgress.total}
+                </span>
+              )}
+              {!scanProgress && scanMetrics && (
+                <span
+                  className="ml-2 text-gray-400"
+                  title={scanMetrics.detectors
+                    .slice(0, 5)
+                    .map(d => `${d.name}: ${d.totalMs.toFixed(1)}ms (최대 ${d.maxMs.toFixed(1)}ms)`)
+                    .join('\n')}
+                >
+                  · 스캔 {(scanMetrics.elapsedMs / 1000).toFixed(1)}초
+                  {scanMetrics.cached > 0 && ` (캐시 ${scanMetrics.cached}개)`}
+                </span>
+              )}
             </div>
 
             <div className="grid grid-cols-1 md:grid-cols-2 lg:grid-cols-3 gap-4">
               {filteredProjects.map(project => (
                 <ProjectCard
-                  key={project.name}
+                  key={project.path}
                   project={project}
                   tagColors={tagColors}
                   onOpenTagEditor={() => handleOpenTagEditor(project)}
+                  onOpenReadme={() => setReadmeProject(project)}
                 />
               ))}
             </div>
@@ -269,6 +364,14 @@ export default function Dashboard() {
           onRefreshDefinitions={fetchTagDefinitions}
         />
       )}
+
+      {/* README 모달 */}
+      {readmeProject && (
+        <ReadmeViewer
+          project={readmeProject}
+          onClose={() => setReadmeProject(null)}
+        />
+      )}
     </div>
   );
 }
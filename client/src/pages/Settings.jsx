@@ -3,12 +3,16 @@ import { invoke } from '@tauri-apps/api/core';
 import { open } from '@tauri-apps/plugin-dialog';
 
 export default function Settings() {
-  const [scanPath, setScanPath] = useState('');
+  const [scanRoots, setScanRoots] = useState([]);
   const [terminalApp, setTerminalApp] = useState('');
   const [editorCommand, setEditorCommand] = useState('');
   const [excludedFolders, setExcludedFolders] = useState([]);
   const [hideArchived, setHideArchived] = useState(true);
   const [hideHiddenProjects, setHideHiddenProjects] = useState(true);
+  const [maxScanDepth, setMaxScanDepth] = useState(3);
+  const [symlinkPolicy, setSymlinkPolicy] = useState('alias');
+  const [preferredRemote, setPreferredRemote] = useState('');
+  const [preferredLocale, setPreferredLocale] = useState('');
   const [loading, setLoading] = useState(true);
   const [saving, setSaving] = useState(false);
   const [customTerminal, setCustomTerminal] = useState('');
@@ -27,7 +31,12 @@ export default function Settings() {
   async function loadSettings() {
     try {
       const settings = await invoke('get_settings');
-      setScanPath(settings.scanPath);
+      setScanRoots((settings.scanRoots || []).map(root => ({
+        path: root.path,
+        label: root.label || '',
+        excludedFolders: (root.excludedFolders || []).join(', '),
+        defaultTags: (root.defaultTags || []).join(', '),
+      })));
 
       // 터미널 앱 설정
       if (terminalOptions.includes(settings.terminalApp)) {
@@ -52,6 +61,10 @@ export default function Settings() {
       setExcludedFolders(settings.excludedFolders || []);
       setHideArchived(settings.hideArchived ?? true);
       setHideHiddenProjects(settings.hideHiddenProjects ?? true);
+      setMaxScanDepth(settings.maxScanDepth ?? 3);
+      setSymlinkPolicy(settings.symlinkPolicy || 'alias');
+      setPreferredRemote(settings.preferredRemote || '');
+      setPreferredLocale(settings.preferredLocale || '');
     } catch (error) {
       console.error('Error loading settings:', error);
     } finally {
@@ -79,7 +92,7 @@ export default function Settings() {
     }
   }
 
-  async function handleSelectFolder() {
+  async function handleSelectFolder(index) {
     try {
       console.log('Opening folder dialog...');
       const selected = await open({
@@ -91,7 +104,13 @@ export default function Settings() {
       console.log('Selected folder:', selected);
 
       if (selected) {
-        setScanPath(selected);
+        if (index === undefined) {
+          if (!scanRoots.some(root => root.path === selected)) {
+            setScanRoots([...scanRoots, { path: selected, label: '', excludedFolders: '', defaultTags: '' }]);
+          }
+        } else {
+          updateScanRoot(index, 'path', selected);
+        }
       }
     } catch (error) {
       console.error('Error selecting folder:', error);
@@ -99,6 +118,18 @@ export default function Settings() {
     }
   }
 
+  function updateScanRoot(index, field, value) {
+    setScanRoots(scanRoots.map((root, i) => (i === index ? { ...root, [field]: value } : root)));
+  }
+
+  function handleRemoveScanRoot(index) {
+    setScanRoots(scanRoots.filter((_, i) => i !== index));
+  }
+
+  function splitList(value) {
+    return value.split(',').map(item => item.trim()).filter(Boolean);
+  }
+
   function handleAddExcludedFolder() {
     const folder = newFolder.trim();
     if (folder && !excludedFolders.includes(folder)) {
@@ -117,14 +148,26 @@ export default function Settings() {
       const finalTerminalApp = showCustomTerminal ? customTerminal : terminalApp;
       const finalEditorCommand = showCustomEditor ? customEditor : editorCommand;
 
+      const roots = scanRoots.map(root => ({
+        path: root.path,
+        label: root.label.trim() || null,
+        excludedFolders: splitList(root.excludedFolders),
+        defaultTags: splitList(root.defaultTags),
+      }));
+
       const result = await invoke('update_settings', {
         settings: {
-          scanPath,
+          scanPath: roots[0]?.path || '',
+          scanRoots: roots,
           terminalApp: finalTerminalApp,
           editorCommand: finalEditorCommand,
           excludedFolders,
           hideArchived,
-          hideHiddenProjects
+          hideHiddenProjects,
+          maxScanDepth,
+          symlinkPolicy,
+          preferredRemote: preferredRemote.trim() || null,
+          preferredLocale: preferredLocale.trim() || null
         }
       });
 
@@ -154,27 +197,103 @@ export default function Settings() {
       <h1 className="text-2xl font-bold mb-6">설정</h1>
 
       <div className="bg-white rounded-lg shadow p-6 space-y-6">
-        {/* 프로젝트 폴더 */}
+        {/* 스캔 루트 */}
         <div>
           <label className="block mb-2 font-medium">
             프로젝트 부모 폴더
           </label>
-          <div className="flex gap-2">
-            <input
-              type="text"
-              value={scanPath}
-              readOnly
-              className="flex-1 px-3 py-2 border rounded bg-gray-50"
-            />
-            <button
-              onClick={handleSelectFolder}
-              className="px-4 py-2 bg-blue-500 text-white rounded hover:bg-blue-600 transition-colors"
-            >
-              폴더 선택
-            </button>
+          <div className="space-y-3">
+            {scanRoots.map((root, index) => (
+              <div key={index} className="border rounded p-3 space-y-2">
+                <div className="flex gap-2">
+                  <input
+                    type="text"
+                    value={root.path}
+                    readOnly
+                    className="flex-1 px-3 py-2 border rounded bg-gray-50"
+                  />
+                  <button
+                    onClick={() => handleSelectFolder(index)}
+                    className="px-3 py-2 bg-gray-100 rounded hover:bg-gray-200 transition-colors"
+                  >
+                    변경
+                  </button>
+                  <button
+                    onClick={() => handleRemoveScanRoot(index)}
+                    className="px-3 py-2 text-gray-500 hover:text-red-500 transition-colors"
+                  >
+                    ×
+                  </button>
+                </div>
+                <input
+                  type="text"
+                  value={root.label}
+                  onChange={(e) => updateScanRoot(index, 'label', e.target.value)}
+                  placeholder="이름 (예: 회사, 개인)"
+                  className="w-full px-3 py-2 border rounded focus:outline-none focus:ring-2 focus:ring-blue-500"
+                />
+                <input
+                  type="text"
+                  value={root.excludedFolders}
+                  onChange={(e) => updateScanRoot(index, 'excludedFolders', e.target.value)}
+                  placeholder="이 폴더에서만 제외할 폴더 (쉼표로 구분)"
+                  className="w-full px-3 py-2 border rounded focus:outline-none focus:ring-2 focus:ring-blue-500"
+                />
+                <input
+                  type="text"
+                  value={root.defaultTags}
+                  onChange={(e) => updateScanRoot(index, 'defaultTags', e.target.value)}
+                  placeholder="기본 구분 태그 (쉼표로 구분)"
+                  className="w-full px-3 py-2 border rounded focus:outline-none focus:ring-2 focus:ring-blue-500"
+                />
+              </div>
+            ))}
           </div>
+          <button
+            onClick={() => handleSelectFolder()}
+            className="mt-3 px-4 py-2 bg-blue-500 text-white rounded hover:bg-blue-600 transition-colors"
+          >
+            폴더 추가
+          </button>
           <p className="text-sm text-gray-500 mt-2">
-            이 폴더의 하위 프로젝트들이 스캔됩니다.
+            각 폴더의 하위 프로젝트들이 스캔됩니다. 기본 구분 태그는 태그를 지정하지 않은 프로젝트에 적용됩니다.
+          </p>
+        </div>
+
+        {/* 스캔 깊이 */}
+        <div>
+          <label className="block mb-2 font-medium">
+            스캔 깊이
+          </label>
+          <input
+            type="number"
+            min="1"
+            max="10"
+            value={maxScanDepth}
+            onChange={(e) => setMaxScanDepth(Math.max(1, parseInt(e.target.value, 10) || 1))}
+            className="w-24 px-3 py-2 border rounded focus:outline-none focus:ring-2 focus:ring-blue-500"
+          />
+          <p className="text-sm text-gray-500 mt-2">
+            프로젝트 표식(.git, package.json, Cargo.toml, .code-tare 등)이 없는 폴더는 이 깊이까지 내려가며 프로젝트를 찾습니다. 1이면 바로 아래 폴더만 스캔합니다.
+          </p>
+        </div>
+
+        {/* 심볼릭 링크 */}
+        <div>
+          <label className="block mb-2 font-medium">
+            심볼릭 링크 폴더
+          </label>
+          <select
+            value={symlinkPolicy}
+            onChange={(e) => setSymlinkPolicy(e.target.value)}
+            className="w-full px-3 py-2 border rounded focus:outline-none focus:ring-2 focus:ring-blue-500"
+          >
+            <option value="alias">별칭으로 표시</option>
+            <option value="follow">따라가기</option>
+            <option value="skip">건너뛰기</option>
+          </select>
+          <p className="text-sm text-gray-500 mt-2">
+            별칭으로 표시: 프로젝트를 가리키는 링크는 실제 폴더의 프로젝트에 별칭으로 표시합니다. 따라가기: 링크된 그룹 폴더 안까지 탐색합니다. 어느 경우든 같은 실제 폴더는 한 번만 표시됩니다.
           </p>
         </div>
 
@@ -236,11 +355,45 @@ export default function Settings() {
           </p>
         </div>
 
+        {/* 기본 원격 저장소 */}
+        <div>
+          <label className="block mb-2 font-medium">
+            기본 원격 저장소
+          </label>
+          <input
+            type="text"
+            value={preferredRemote}
+            onChange={(e) => setPreferredRemote(e.target.value)}
+            placeholder="origin"
+            className="w-full px-3 py-2 border rounded focus:outline-none focus:ring-2 focus:ring-blue-500"
+          />
+          <p className="text-sm text-gray-500 mt-2">
+            저장소 버튼으로 열 원격 이름 (예: upstream). 해당 원격이 없으면 origin, 그다음 첫 번째 원격을 엽니다.
+          </p>
+        </div>
+
+        {/* README 언어 */}
+        <div>
+          <label className="block mb-2 font-medium">
+            README 언어
+          </label>
+          <input
+            type="text"
+            value={preferredLocale}
+            onChange={(e) => setPreferredLocale(e.target.value)}
+            placeholder="시스템 언어"
+            className="w-full px-3 py-2 border rounded focus:outline-none focus:ring-2 focus:ring-blue-500"
+          />
+          <p className="text-sm text-gray-500 mt-2">
+            프로젝트 README를 볼 때 이 언어의 파일(예: ko → README.ko.md)을 먼저 찾습니다. 없으면 README.md, README.rst, README.txt 순으로 엽니다.
+          </p>
+        </div>
+
         {/* 제외 폴더 */}
         <div className="border-t pt-6">
           <h3 className="font-medium mb-4">스캔 제외 폴더</h3>
           <p className="text-sm text-gray-500 mb-3">
-            프로젝트 스캔 시 제외할 폴더 이름 (예: node_modules, .git)
+            프로젝트 스캔 시 제외할 폴더 이름 또는 .gitignore 형식 패턴 (예: node_modules, tmp-*, /clients/old, !tmp-keep)
           </p>
           <div className="flex gap-2 mb-3">
             <input
@@ -252,7 +405,7 @@ export default function Settings() {
                   handleAddExcludedFolder();
                 }
               }}
-              placeholder="폴더 이름 입력"
+              placeholder="폴더 이름 또는 패턴 입력"
               className="flex-1 px-3 py-2 border rounded focus:outline-none focus:ring-2 focus:ring-blue-500"
             />
             <button
@@ -0,0 +1,254 @@
+use crate::detectors::get_detection_rules_file;
+use crate::git::resolve_git_dirs;
+use crate::scanner::{Project, SubPackage};
+use crate::settings::{get_data_dir, ScanRoot};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Component, Path, PathBuf};
+use std::sync::{Mutex, MutexGuard};
+use std::time::UNIX_EPOCH;
+
+/// 캐시 형식 버전 (감지기나 Project 구조가 바뀌면 올려서 기존 캐시 무효화)
+pub const SCAN_CACHE_VERSION: u32 = 4;
+
+/// Git 디렉토리(작업 트리별)에서 변경을 확인할 파일
+const GIT_DIR_FILES: &[&str] = &["HEAD", "index", "logs/HEAD"];
+
+/// Git 공통 디렉토리에서 변경을 확인할 파일
+const GIT_COMMON_FILES: &[&str] = &[
+    "config",
+    "packed-refs",
+    "FETCH_HEAD",
+    "logs/refs/stash",
+    "worktrees",
+];
+
+/// 캐시 파일 읽기-수정-저장을 한 번에 하나씩만 하도록 막는 잠금 (전체 스캔과 감시 스레드가 공유)
+static CACHE_LOCK: Mutex<()> = Mutex::new(());
+
+fn get_scan_cache_file() -> PathBuf {
+    get_data_dir().join("scan-cache.json")
+}
+
+/// 캐시 파일 잠금 (캐시를 읽고 다시 저장할 때까지 유지)
+pub fn lock_cache() -> MutexGuard<'static, ()> {
+    CACHE_LOCK.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+/// 파일 하나의 수정 시각과 크기
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct FileStamp {
+    pub path: String,
+    /// UNIX 시각 (나노초)
+    pub modified: u64,
+    pub size: u64,
+}
+
+impl FileStamp {
+    /// 파일이 없거나 읽을 수 없으면 None
+    pub(crate) fn read(path: &Path) -> Option<FileStamp> {
+        let metadata = fs::metadata(path).ok()?;
+        let modified = metadata
+            .modified()
+            .ok()?
+            .duration_since(UNIX_EPOCH)
+            .ok()?
+            .as_nanos() as u64;
+
+        Some(FileStamp {
+            path: path.to_string_lossy().to_string(),
+            modified,
+            size: metadata.len(),
+        })
+    }
+}
+
+/// 캐시된 프로젝트 하나
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CachedProject {
+    pub fingerprint: Vec<FileStamp>,
+    /// 스캔 당시 루트의 기본 태그 (바뀌면 다시 스캔)
+    pub default_tags: Vec<String>,
+    pub project: Project,
+}
+
+/// 프로젝트 경로별 스캔 결과 캐시
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct ScanCache {
+    pub version: u32,
+    /// 스캔 당시 등록된 감지기 이름 (바뀌면 전체 무효화)
+    pub detectors: Vec<String>,
+    /// 스캔 당시 사용자 정의 감지 규칙 파일
+    pub rules: Option<FileStamp>,
+    pub projects: HashMap<String, CachedProject>,
+}
+
+impl ScanCache {
+    /// 현재 감지기 구성에 맞는 빈 캐시
+    pub fn empty(detectors: &[&str]) -> Self {
+        ScanCache {
+            version: SCAN_CACHE_VERSION,
+            detectors: detectors.iter().map(|d| d.to_string()).collect(),
+            rules: FileStamp::read(&get_detection_rules_file()),
+            projects: HashMap::new(),
+        }
+    }
+
+    /// 저장된 캐시 로드 (버전이나 감지기 구성이 다르면 빈 캐시)
+    pub fn load(detectors: &[&str]) -> Self {
+        let empty = ScanCache::empty(detectors);
+        let Ok(content) = fs::read_to_string(get_scan_cache_file()) else {
+            return empty;
+        };
+
+        match serde_json::from_str::<ScanCache>(&content) {
+            Ok(cache)
+                if cache.version == empty.version
+                    && cache.detectors == empty.detectors
+                    && cache.rules == empty.rules =>
+            {
+                cache
+            }
+            Ok(_) => {
+                println!("Scan cache is outdated, rescanning all projects");
+                empty
+            }
+            Err(e) => {
+                println!("Failed to parse scan cache: {}", e);
+                empty
+            }
+        }
+    }
+
+    /// 파일이 바뀌지 않았고 같은 루트/그룹에서 찾은 프로젝트면 캐시된 결과와 지문 반환
+    pub fn lookup(
+        &self,
+        project_path: &Path,
+        group: &Option<String>,
+        root: &ScanRoot,
+    ) -> Option<CachedProject> {
+        let cached = self.projects.get(project_path.to_string_lossy().as_ref())?;
+        let project = &cached.project;
+        let root_label = root.label.clone().filter(|l| !l.trim().is_empty());
+
+        if project.root_path != root.path
+            || project.root_label != root_label
+            || project.group != *group
+            || cached.default_tags != root.default_tags
+        {
+            return None;
+        }
+
+        let fingerprint = project_fingerprint(project_path, &project.packages);
+        if fingerprint != cached.fingerprint {
+            return None;
+        }
+
+        Some(cached.clone())
+    }
+
+    /// 캐시 파일 저장
+    pub fn save(&self) -> Result<(), String> {
+        let content = serde_json::to_string(self)
+            .map_err(|e| format!("Failed to serialize scan cache: {}", e))?;
+        fs::write(get_scan_cache_file(), content)
+            .map_err(|e| format!("Failed to write scan cache file: {}", e))?;
+        Ok(())
+    }
+}
+
+/// 마지막 스캔에서 찾은 프로젝트 경로인지 확인 (별칭 경로 포함, 감지기 구성과 무관)
+pub fn is_cached_project(project_path: &str) -> bool {
+    let Ok(content) = fs::read_to_string(get_scan_cache_file()) else {
+        return false;
+    };
+    let Ok(cache) = serde_json::from_str::<ScanCache>(&content) else {
+        return false;
+    };
+
+    cache.projects.contains_key(project_path)
+        || cache.projects.values().any(|cached| {
+            cached
+                .project
+                .aliases
+                .iter()
+                .any(|alias| alias == project_path)
+        })
+}
+
+/// 폴더 바로 아래 파일들의 지문 (매니페스트, README, CHANGELOG 등)
+fn push_top_level_files(dir: &Path, stamps: &mut Vec<FileStamp>) {
+    // 폴더 자체의 수정 시각은 파일 추가/삭제 시 바뀜
+    stamps.extend(FileStamp::read(dir));
+
+    let Ok(entries) = fs::read_dir(dir) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        if entry.file_type().is_ok_and(|t| t.is_file()) {
+            stamps.extend(FileStamp::read(&entry.path()));
+        }
+    }
+}
+
+/// 프로젝트 변경 여부 판단용 지문
+///
+/// 프로젝트 및 하위 패키지 폴더 바로 아래 파일과 Git 메타데이터(HEAD, index, refs 로그 등)의
+/// 수정 시각과 크기. 하위 폴더 파일만 수정한 경우는 감지하지 않으므로 강제 새로고침 필요
+pub fn project_fingerprint(project_path: &Path, packages: &[SubPackage]) -> Vec<FileStamp> {
+    let mut stamps = Vec::new();
+    push_top_level_files(project_path, &mut stamps);
+    for package in packages {
+        push_top_level_files(&project_path.join(&package.path), &mut stamps);
+    }
+
+    if let Some(dirs) = resolve_git_dirs(project_path) {
+        for file in GIT_DIR_FILES {
+            stamps.extend(FileStamp::read(&dirs.git_dir.join(file)));
+        }
+        for file in GIT_COMMON_FILES {
+            stamps.extend(FileStamp::read(&dirs.common_dir.join(file)));
+        }
+    }
+
+    stamps.sort_by(|a, b| a.path.cmp(&b.path));
+    stamps.dedup_by(|a, b| a.path == b.path);
+    stamps
+}
+
+/// 지문에 포함되는 파일이 있는 폴더 (변경 감시 대상, 하위 폴더는 포함하지 않음)
+pub fn fingerprint_dirs(project_path: &Path, packages: &[SubPackage]) -> Vec<PathBuf> {
+    let mut dirs = vec![project_path.to_path_buf()];
+    for package in packages {
+        let package_path = Path::new(&package.path);
+        if package_path
+            .components()
+            .all(|c| matches!(c, Component::Normal(_)))
+        {
+            dirs.push(project_path.join(package_path));
+        }
+    }
+
+    if let Some(git) = resolve_git_dirs(project_path) {
+        let git_files = GIT_DIR_FILES.iter().map(|file| git.git_dir.join(file));
+        let common_files = GIT_COMMON_FILES
+            .iter()
+            .map(|file| git.common_dir.join(file));
+        for path in git_files.chain(common_files) {
+            // worktrees처럼 폴더인 항목은 폴더 자체도 감시
+            if path.is_dir() {
+                dirs.push(path.clone());
+            }
+            dirs.extend(path.parent().map(Path::to_path_buf));
+        }
+    }
+
+    dirs.retain(|dir| dir.is_dir());
+    dirs.sort();
+    dirs.dedup();
+    dirs
+}
@@ -1,12 +1,30 @@
-use crate::scanner::scan_all_projects;
+use crate::cache::is_cached_project;
+use crate::git::{
+    pick_remote, read_commit_days, resolve_git_dirs, GitRemote, GitStatus, GitSubmodule,
+    GitWorktree,
+};
+use crate::readme::{preferred_locale, read_project_readme, ProjectReadme};
+use crate::recent::{load_last_opened, record_project_opened};
+use crate::release::ReleaseInfo;
+use crate::scanner::{
+    find_project_dirs, scan_all_projects, Diagnostic, NoProgress, Project, ScanMetrics,
+    ScanProgress, ScanReport, SubPackage,
+};
 use crate::settings::{load_settings, save_settings, AppSettings};
 use crate::tags::{
     add_category_tag, delete_category_tag, get_project_tags, load_project_tags,
-    load_tag_colors, load_tag_definitions, set_project_tags, ProjectTags,
+    load_tag_colors, load_tag_definitions, migrate_legacy_tags, set_project_tags, ProjectTags,
     TagColors, TagDefinitions,
 };
+use chrono::Local;
 use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::thread;
 use tauri::command;
+use tauri::ipc::Channel;
+use tauri::{AppHandle, Manager};
 
 #[derive(Debug, Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -14,6 +32,13 @@ pub struct ProjectsResponse {
     pub success: bool,
     pub count: usize,
     pub projects: Vec<ProjectWithTags>,
+    /// 스캔 소요 시간 통계 (실패 시 None)
+    pub metrics: Option<ScanMetrics>,
+    /// 읽지 못한 스캔 루트, 감지 규칙 파일, 옮기지 못한 태그 오류 (일부 루트만 실패한 경우에도 포함)
+    pub scan_errors: Vec<String>,
+    /// 스캔 전체 실패 사유
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
 }
 
 #[derive(Debug, Serialize, Clone)]
@@ -21,14 +46,35 @@ pub struct ProjectsResponse {
 pub struct ProjectWithTags {
     pub name: String,
     pub path: String,
+    pub group: Option<String>,
+    pub root_path: String,
+    pub root_label: Option<String>,
     #[serde(rename = "type")]
     pub project_type: Vec<String>,
     pub description: String,
     pub tech_stack: Vec<String>,
-    pub last_modified: String,
+    pub targets: Vec<String>,
+    pub packages: Vec<SubPackage>,
+    pub release: ReleaseInfo,
+    pub last_modified: Option<String>,
+    pub last_commit: Option<String>,
+    pub last_edited: Option<String>,
+    /// Code-tare에서 마지막으로 연 시각
+    pub last_opened: Option<String>,
+    /// 웹으로 열 원격 저장소 주소 (설정의 기본 원격 → origin → 첫 번째)
     pub git_remote: Option<String>,
+    pub git_remotes: Vec<GitRemote>,
+    pub git_status: Option<GitStatus>,
+    pub worktree: Option<GitWorktree>,
+    pub submodules: Vec<GitSubmodule>,
     pub has_tests: bool,
     pub has_ci: bool,
+    /// 깨진 매니페스트, 읽을 수 없는 파일 등 스캔 중 발견한 문제
+    pub diagnostics: Vec<Diagnostic>,
+    /// 시간 또는 파일 크기 예산을 넘어 일부만 스캔한 프로젝트
+    pub partial: bool,
+    /// 같은 프로젝트를 가리키는 다른 경로 (심볼릭 링크 등)
+    pub aliases: Vec<String>,
     pub tags: ProjectTags,
 }
 
@@ -46,6 +92,31 @@ pub struct SimpleResponse {
     pub message: Option<String>,
 }
 
+/// 프로젝트별 날짜별 커밋 수
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectActivity {
+    pub name: String,
+    pub path: String,
+    pub total: usize,
+    /// "YYYY-MM-DD" → 커밋 수 (커밋이 없는 날은 생략)
+    pub daily: BTreeMap<String, usize>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ActivityResponse {
+    pub success: bool,
+    /// 집계 시작일과 종료일 (로컬 날짜, 포함)
+    pub since: String,
+    pub until: String,
+    pub total: usize,
+    /// 전체 프로젝트 합계 (같은 저장소의 worktree는 한 번만 집계)
+    pub workspace: BTreeMap<String, usize>,
+    /// 기간 내 커밋이 있는 프로젝트 (커밋 수 내림차순)
+    pub projects: Vec<ProjectActivity>,
+}
+
 #[derive(Debug, Serialize)]
 pub struct ReadmeResponse {
     pub success: bool,
@@ -55,6 +126,15 @@ pub struct ReadmeResponse {
     pub message: Option<String>,
 }
 
+#[derive(Debug, Serialize)]
+pub struct ProjectReadmeResponse {
+    pub success: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub readme: Option<ProjectReadme>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+
 #[derive(Debug, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct TagManageResponse {
@@ -76,58 +156,275 @@ pub struct TagManageRequest {
     pub tag: String,
 }
 
-/// 모든 프로젝트 목록 반환
+/// 스캔 결과에 태그, 최근 열람 시각, 대표 원격 저장소를 붙여 프론트엔드용으로 변환
+pub(crate) fn project_with_tags(
+    project: Project,
+    project_tags: &HashMap<String, ProjectTags>,
+    last_opened: &HashMap<String, String>,
+    settings: &AppSettings,
+) -> ProjectWithTags {
+    // 경로 기준 태그 우선, 없으면 별칭 경로 (폴더 이름 기준 태그는 migrate_legacy_tags에서만 사용)
+    let tags = project_tags
+        .get(&project.path)
+        .or_else(|| project.aliases.iter().find_map(|alias| project_tags.get(alias)))
+        .cloned()
+        .unwrap_or_else(|| ProjectTags {
+            custom_title: None,
+            progress: "계획중".to_string(),
+            categories: project.default_categories.clone(),
+            favorite: false,
+            archived: false,
+            notes: String::new(),
+        });
+
+    ProjectWithTags {
+        last_opened: last_opened
+            .get(&project.path)
+            .or_else(|| project.aliases.iter().find_map(|alias| last_opened.get(alias)))
+            .cloned(),
+        name: project.name,
+        path: project.path,
+        group: project.group,
+        root_path: project.root_path,
+        root_label: project.root_label,
+        project_type: project.project_type,
+        description: project.description,
+        tech_stack: project.tech_stack,
+        targets: project.targets,
+        packages: project.packages,
+        release: project.release,
+        last_modified: project.last_modified,
+        last_commit: project.last_commit,
+        last_edited: project.last_edited,
+        git_remote: pick_remote(&project.git_remotes, settings.preferred_remote.as_deref())
+            .and_then(|remote| remote.web_url.clone()),
+        git_remotes: project.git_remotes,
+        git_status: project.git_status,
+        worktree: project.worktree,
+        submodules: project.submodules,
+        has_tests: project.has_tests,
+        has_ci: project.has_ci,
+        diagnostics: project.diagnostics,
+        partial: project.partial,
+        aliases: project.aliases,
+        tags,
+    }
+}
+
+/// 전체 스캔 뒤 이전 버전의 폴더 이름 기준 태그를 옮김 (읽지 못한 루트나 규칙이 있으면 건너뜀)
+///
+/// 같은 이름의 프로젝트 때문에 옮기지 못한 태그는 스캔 오류로 알리고, 태그를 새로 받은 프로젝트 경로를 반환
+fn migrate_tags_after_scan(report: &mut ScanReport) -> Vec<String> {
+    if !report.errors.is_empty() {
+        return Vec::new();
+    }
+    match migrate_legacy_tags(&report.projects) {
+        Ok(migration) => {
+            report.errors.extend(migration.ambiguous);
+            migration.migrated
+        }
+        Err(e) => {
+            println!("Failed to migrate legacy tags: {}", e);
+            Vec::new()
+        }
+    }
+}
+
+/// 모든 프로젝트 목록 반환 (force_rescan이면 캐시를 무시하고 전체 다시 스캔)
 #[command]
-pub fn get_projects() -> ProjectsResponse {
+pub fn get_projects(force_rescan: Option<bool>) -> ProjectsResponse {
     let settings = load_settings();
-    match scan_all_projects(&settings.scan_path, &settings.excluded_folders) {
-        Ok(projects) => {
+    match scan_all_projects(
+        &settings.effective_scan_roots(),
+        &settings.scan_options(),
+        force_rescan.unwrap_or(false),
+        &NoProgress,
+    ) {
+        Ok(mut report) => {
+            migrate_tags_after_scan(&mut report);
             let project_tags = load_project_tags();
+            let last_opened = load_last_opened();
 
-            let projects_with_tags: Vec<ProjectWithTags> = projects
+            let projects_with_tags: Vec<ProjectWithTags> = report
+                .projects
                 .into_iter()
-                .map(|project| {
-                    let tags = project_tags
-                        .get(&project.name)
-                        .cloned()
-                        .unwrap_or_else(|| ProjectTags {
-                            custom_title: None,
-                            progress: "계획중".to_string(),
-                            categories: Vec::new(),
-                            favorite: false,
-                            archived: false,
-                            notes: String::new(),
-                        });
-
-                    ProjectWithTags {
-                        name: project.name,
-                        path: project.path,
-                        project_type: project.project_type,
-                        description: project.description,
-                        tech_stack: project.tech_stack,
-                        last_modified: project.last_modified,
-                        git_remote: project.git_remote,
-                        has_tests: project.has_tests,
-                        has_ci: project.has_ci,
-                        tags,
-                    }
-                })
+                .map(|project| project_with_tags(project, &project_tags, &last_opened, &settings))
                 .collect();
 
             ProjectsResponse {
                 success: true,
                 count: projects_with_tags.len(),
                 projects: projects_with_tags,
+                metrics: Some(report.metrics),
+                scan_errors: report.errors,
+                message: None,
             }
         }
-        Err(_e) => ProjectsResponse {
-            success: false,
-            count: 0,
-            projects: Vec::new(),
-        },
+        Err(e) => {
+            println!("Failed to scan projects: {}", e);
+            ProjectsResponse {
+                success: false,
+                count: 0,
+                projects: Vec::new(),
+                metrics: None,
+                scan_errors: e.lines().map(|line| line.to_string()).collect(),
+                message: Some(e),
+            }
+        }
+    }
+}
+
+/// 진행 중인 스캔 번호 (새 스캔을 시작하면 증가해 이전 스캔이 취소됨)
+static CURRENT_SCAN_ID: AtomicU64 = AtomicU64::new(0);
+
+/// start_scan 진행 이벤트
+#[derive(Debug, Serialize, Clone)]
+#[serde(
+    rename_all = "camelCase",
+    rename_all_fields = "camelCase",
+    tag = "event",
+    content = "data"
+)]
+pub enum ScanEvent {
+    /// 폴더 탐색이 끝나 스캔할 프로젝트 수가 정해짐
+    Started {
+        scan_id: u64,
+        total: usize,
+    },
+    /// 프로젝트 하나 스캔 완료 (index는 전체 결과에서의 순서, 도착 순서는 다를 수 있음)
+    Project {
+        scan_id: u64,
+        index: usize,
+        cached: bool,
+        project: Box<ProjectWithTags>,
+    },
+    /// 스캔 완료 요약 (errors는 읽지 못한 스캔 루트, 감지 규칙 파일, 옮기지 못한 태그)
+    Finished {
+        scan_id: u64,
+        count: usize,
+        metrics: ScanMetrics,
+        errors: Vec<String>,
+        /// 스캔 뒤 폴더 이름 기준 태그를 경로 기준으로 옮겨 태그가 바뀐 프로젝트
+        retagged: Vec<ProjectWithTags>,
+    },
+    /// 새 스캔이 시작되어 중단됨
+    Cancelled {
+        scan_id: u64,
+        scanned: usize,
+    },
+    Failed {
+        scan_id: u64,
+        message: String,
+    },
+}
+
+/// 스캔 진행 상황을 채널로 전송
+struct ChannelProgress<'a> {
+    scan_id: u64,
+    channel: &'a Channel<ScanEvent>,
+    project_tags: HashMap<String, ProjectTags>,
+    last_opened: HashMap<String, String>,
+    settings: &'a AppSettings,
+}
+
+impl ScanProgress for ChannelProgress<'_> {
+    fn started(&self, total: usize) {
+        self.channel
+            .send(ScanEvent::Started {
+                scan_id: self.scan_id,
+                total,
+            })
+            .ok();
+    }
+
+    fn scanned(&self, index: usize, project: &Project, cached: bool) {
+        if self.is_cancelled() {
+            return;
+        }
+        let project = Box::new(project_with_tags(
+            project.clone(),
+            &self.project_tags,
+            &self.last_opened,
+            self.settings,
+        ));
+        self.channel
+            .send(ScanEvent::Project {
+                scan_id: self.scan_id,
+                index,
+                cached,
+                project,
+            })
+            .ok();
+    }
+
+    fn is_cancelled(&self) -> bool {
+        CURRENT_SCAN_ID.load(Ordering::SeqCst) != self.scan_id
     }
 }
 
+/// 백그라운드에서 프로젝트 스캔을 시작하고 진행 상황을 채널로 전송, 스캔 번호 반환
+///
+/// 이미 진행 중인 스캔은 취소됨
+#[command]
+pub fn start_scan(force_rescan: Option<bool>, on_event: Channel<ScanEvent>) -> u64 {
+    let scan_id = CURRENT_SCAN_ID.fetch_add(1, Ordering::SeqCst) + 1;
+
+    thread::spawn(move || {
+        let settings = load_settings();
+        let progress = ChannelProgress {
+            scan_id,
+            channel: &on_event,
+            project_tags: load_project_tags(),
+            last_opened: load_last_opened(),
+            settings: &settings,
+        };
+
+        let event = match scan_all_projects(
+            &settings.effective_scan_roots(),
+            &settings.scan_options(),
+            force_rescan.unwrap_or(false),
+            &progress,
+        ) {
+            Ok(report) if report.cancelled => ScanEvent::Cancelled {
+                scan_id,
+                scanned: report.projects.len(),
+            },
+            Ok(mut report) => {
+                let migrated = migrate_tags_after_scan(&mut report);
+                let retagged = if migrated.is_empty() {
+                    Vec::new()
+                } else {
+                    let project_tags = load_project_tags();
+                    report
+                        .projects
+                        .iter()
+                        .filter(|project| migrated.contains(&project.path))
+                        .map(|project| {
+                            project_with_tags(
+                                project.clone(),
+                                &project_tags,
+                                &progress.last_opened,
+                                &settings,
+                            )
+                        })
+                        .collect()
+                };
+                ScanEvent::Finished {
+                    scan_id,
+                    count: report.projects.len(),
+                    metrics: report.metrics,
+                    errors: report.errors,
+                    retagged,
+                }
+            }
+            Err(message) => ScanEvent::Failed { scan_id, message },
+        };
+        on_event.send(event).ok();
+    });
+
+    scan_id
+}
+
 /// 사용 가능한 모든 태그 반환
 #[command]
 pub fn get_available_tags() -> TagsResponse {
@@ -148,25 +445,25 @@ pub fn get_available_tags() -> TagsResponse {
 
 /// 특정 프로젝트의 태그 반환
 #[command]
-pub fn get_tags(project_name: String) -> Result<ProjectTags, String> {
-    Ok(get_project_tags(&project_name))
+pub fn get_tags(project_path: String) -> Result<ProjectTags, String> {
+    Ok(get_project_tags(&project_path))
 }
 
 /// 특정 프로젝트의 태그 저장
 #[command]
-pub fn save_tags(project_name: String, tags: ProjectTags) -> SimpleResponse {
-    println!("🔍 save_tags 호출됨: project_name={}, tags={:?}", project_name, tags);
+pub fn save_tags(project_path: String, tags: ProjectTags) -> SimpleResponse {
+    println!("🔍 save_tags 호출됨: project_path={}, tags={:?}", project_path, tags);
 
-    match set_project_tags(&project_name, tags) {
+    match set_project_tags(&project_path, tags) {
         Ok(_) => {
-            println!("✅ 태그 저장 성공: {}", project_name);
+            println!("✅ 태그 저장 성공: {}", project_path);
             SimpleResponse {
                 success: true,
                 message: None,
             }
         }
         Err(e) => {
-            println!("❌ 태그 저장 실패: {} - 오류: {}", project_name, e);
+            println!("❌ 태그 저장 실패: {} - 오류: {}", project_path, e);
             SimpleResponse {
                 success: false,
                 message: Some(e),
@@ -258,6 +555,7 @@ pub async fn open_project(path: String, app: String, url: Option<String>) -> Sim
                 }
             }
 
+            record_project_opened(&path).ok();
             SimpleResponse {
                 success: true,
                 message: None,
@@ -275,6 +573,7 @@ pub async fn open_project(path: String, app: String, url: Option<String>) -> Sim
                 }
             }
 
+            record_project_opened(&path).ok();
             SimpleResponse {
                 success: true,
                 message: None,
@@ -291,6 +590,7 @@ pub async fn open_project(path: String, app: String, url: Option<String>) -> Sim
                 }
             }
 
+            record_project_opened(&path).ok();
             SimpleResponse {
                 success: true,
                 message: None,
@@ -347,6 +647,59 @@ pub fn update_settings(settings: AppSettings) -> SimpleResponse {
     }
 }
 
+/// 최근 기간의 날짜별 커밋 수 (로컬 git 기록 기준, 기본 365일)
+#[command]
+pub fn get_commit_activity(days: Option<u32>) -> ActivityResponse {
+    let settings = load_settings();
+    let days = days.unwrap_or(365).clamp(1, 3650);
+
+    let today = Local::now().date_naive();
+    let since = today - chrono::Duration::days(i64::from(days) - 1);
+
+    let project_dirs = find_project_dirs(&settings.effective_scan_roots(), &settings.scan_options());
+
+    let mut workspace: BTreeMap<String, usize> = BTreeMap::new();
+    let mut counted_repositories: HashSet<PathBuf> = HashSet::new();
+    let mut projects = Vec::new();
+
+    for project_dir in project_dirs {
+        let daily = read_commit_days(&project_dir, since);
+        if daily.is_empty() {
+            continue;
+        }
+
+        // 같은 저장소를 공유하는 worktree는 전체 합계에 한 번만 반영
+        let repository = resolve_git_dirs(&project_dir).map(|dirs| dirs.common_dir);
+        if repository.map_or(true, |repo| counted_repositories.insert(repo)) {
+            for (day, count) in &daily {
+                *workspace.entry(day.clone()).or_insert(0) += count;
+            }
+        }
+
+        projects.push(ProjectActivity {
+            name: project_dir
+                .file_name()
+                .unwrap_or_default()
+                .to_string_lossy()
+                .to_string(),
+            path: project_dir.to_string_lossy().to_string(),
+            total: daily.values().sum(),
+            daily,
+        });
+    }
+
+    projects.sort_by(|a, b| b.total.cmp(&a.total).then_with(|| a.name.cmp(&b.name)));
+
+    ActivityResponse {
+        success: true,
+        since: since.format("%Y-%m-%d").to_string(),
+        until: today.format("%Y-%m-%d").to_string(),
+        total: workspace.values().sum(),
+        workspace,
+        projects,
+    }
+}
+
 /// 사용 설명서 내용 가져오기
 #[command]
 pub fn get_readme() -> ReadmeResponse {
@@ -359,3 +712,124 @@ pub fn get_readme() -> ReadmeResponse {
         message: None,
     }
 }
+
+/// 마지막 스캔에서 찾았거나 지금 스캔 루트에서 찾은 프로젝트 폴더의 실제 경로
+///
+/// 프론트엔드가 보낸 임의의 경로로 asset 프로토콜 허용 범위를 넓히지 않도록
+/// 프로젝트 경로(또는 별칭 경로)와 정확히 일치하는지 확인 (스캔 루트 안의 아무 폴더는 허용하지 않음)
+fn known_project_dir(project_path: &str, settings: &AppSettings) -> Result<PathBuf, String> {
+    let is_known = is_cached_project(project_path)
+        || find_project_dirs(&settings.effective_scan_roots(), &settings.scan_options())
+            .iter()
+            .any(|dir| dir == Path::new(project_path));
+    if !is_known {
+        return Err(format!("Not a scanned project: {}", project_path));
+    }
+
+    Path::new(project_path)
+        .canonicalize()
+        .map_err(|e| format!("Failed to open project folder: {}", e))
+}
+
+/// 프로젝트 README 원문, 렌더링한 HTML과 실제 파일 경로 가져오기 (설정한 언어의 README 우선)
+///
+/// README의 이미지와 링크가 열리도록 프로젝트 폴더를 asset 프로토콜 허용 범위에 추가
+/// (스캔한 프로젝트 폴더만)
+#[command]
+pub fn get_project_readme(app: AppHandle, project_path: String) -> ProjectReadmeResponse {
+    let settings = load_settings();
+    let locale = preferred_locale(settings.preferred_locale.as_deref());
+
+    let readme = known_project_dir(&project_path, &settings).and_then(|project_dir| {
+        let readme = read_project_readme(&project_path, locale.as_deref())?;
+        if let Err(e) = app.asset_protocol_scope().allow_directory(&project_dir, true) {
+            println!("Failed to allow README assets of {}: {}", project_path, e);
+        }
+        Ok(readme)
+    });
+
+    match readme {
+        Ok(readme) => ProjectReadmeResponse {
+            success: true,
+            readme: Some(readme),
+            message: None,
+        },
+        Err(e) => {
+            println!("Failed to read README of {}: {}", project_path, e);
+            ProjectReadmeResponse {
+                success: false,
+                readme: None,
+                message: Some(e),
+            }
+        }
+    }
+}
+
+/// README 링크 등 외부 주소를 기본 브라우저나 메일 앱으로 열기 (http, https, mailto만 허용)
+#[command]
+pub fn open_url(url: String) -> SimpleResponse {
+    let url = url.trim();
+    let lower = url.to_lowercase();
+    if !["http://", "https://", "mailto:"].iter().any(|scheme| lower.starts_with(scheme)) {
+        return SimpleResponse {
+            success: false,
+            message: Some(format!("Unsupported URL: {}", url)),
+        };
+    }
+
+    #[cfg(target_os = "macos")]
+    {
+        if let Err(e) = std::process::Command::new("open").arg(url).spawn() {
+            return SimpleResponse {
+                success: false,
+                message: Some(format!("Failed to open URL: {}", e)),
+            };
+        }
+    }
+
+    SimpleResponse {
+        success: true,
+        message: None,
+    }
+}
+
+/// README가 가리키는 프로젝트 안의 파일을 Finder에서 선택해 보여주기 (파일을 실행하지 않음)
+#[command]
+pub fn reveal_project_file(project_path: String, file_path: String) -> SimpleResponse {
+    let settings = load_settings();
+    let target = known_project_dir(&project_path, &settings).and_then(|project_dir| {
+        let file = Path::new(&file_path)
+            .canonicalize()
+            .map_err(|e| format!("Failed to open {}: {}", file_path, e))?;
+        if file.starts_with(&project_dir) {
+            Ok(file)
+        } else {
+            Err(format!("{} is outside the project folder", file_path))
+        }
+    });
+    #[cfg_attr(not(target_os = "macos"), allow(unused_variables))]
+    let file = match target {
+        Ok(file) => file,
+        Err(e) => {
+            return SimpleResponse {
+                success: false,
+                message: Some(e),
+            }
+        }
+    };
+
+    #[cfg(target_os = "macos")]
+    {
+        if let Err(e) = std::process::Command::new("open").arg("-R").arg(&file).spawn() {
+            return SimpleResponse {
+                success: false,
+                message: Some(format!("Failed to open Finder: {}", e)),
+            };
+        }
+    }
+
+    SimpleResponse {
+        success: true,
+        message: None,
+    }
+}
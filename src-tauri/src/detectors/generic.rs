@@ -0,0 +1,91 @@
+use super::{read_error_message, read_text, Detection, ProjectDetector};
+use std::path::Path;
+
+/// README.md 내용에서 설명 추출
+fn extract_description(content: &str) -> Option<String> {
+    let lines: Vec<&str> = content.lines().filter(|l| !l.trim().is_empty()).collect();
+    let mut description = String::new();
+    let mut found_title = false;
+
+    for line in lines {
+        if line.starts_with('#') {
+            found_title = true;
+            let title = line.trim_start_matches('#').trim();
+            description = title.to_string();
+            continue;
+        }
+
+        if found_title && !line.starts_with('#') && line.len() > 10 {
+            description.push(' ');
+            description.push_str(line.trim());
+            break;
+        }
+    }
+
+    if description.is_empty() {
+        return None;
+    }
+
+    Some(description.chars().take(150).collect())
+}
+
+/// README.md 기반 설명 감지기 (매니페스트 설명이 없을 때 사용)
+pub struct ReadmeDetector;
+
+impl ProjectDetector for ReadmeDetector {
+    fn name(&self) -> &str {
+        "readme"
+    }
+
+    fn priority(&self) -> i32 {
+        20
+    }
+
+    fn detect(&self, project_dir: &Path) -> Option<Detection> {
+        let content = match read_text(&project_dir.join("README.md")) {
+            Ok(content) => content,
+            Err(e) => {
+                // README는 설명에만 쓰이므로 읽지 못해도 경고로 처리
+                let mut detection = Detection::default();
+                detection.warn("README.md".to_string(), read_error_message(&e)?);
+                return Some(detection);
+            }
+        };
+
+        Some(Detection {
+            description: extract_description(&content),
+            ..Detection::default()
+        })
+    }
+}
+
+/// CI 설정 및 테스트 폴더 감지기
+pub struct LayoutDetector;
+
+impl ProjectDetector for LayoutDetector {
+    fn name(&self) -> &str {
+        "layout"
+    }
+
+    fn priority(&self) -> i32 {
+        0
+    }
+
+    fn detect(&self, project_dir: &Path) -> Option<Detection> {
+        // CI 파일 확인
+        let ci_paths = [
+            project_dir.join(".github").join("workflows"),
+            project_dir.join(".gitlab-ci.yml"),
+            project_dir.join(".travis.yml"),
+        ];
+
+        // 테스트 폴더 확인
+        let test_dirs = ["test", "tests", "__tests__", "spec"];
+
+        Some(Detection {
+            has_ci: ci_paths.iter().any(|p| p.exists()),
+            has_tests: test_dirs.iter().any(|dir| project_dir.join(dir).exists()),
+            ..Detection::default()
+        })
+    }
+}
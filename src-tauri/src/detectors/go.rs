@@ -0,0 +1,237 @@
+use super::{extend_unique, read_manifest, relative_path, Detection, ProjectDetector};
+use crate::scanner::SubPackage;
+use std::fs;
+use std::path::Path;
+
+/// go.mod 파싱 결과
+#[derive(Default)]
+struct GoModule {
+    module_path: Option<String>,
+    go_version: Option<String>,
+    requires: Vec<String>,
+}
+
+/// go.mod 내용 파싱 (module, go, require 블록)
+fn parse_go_mod(content: &str) -> GoModule {
+    let mut module = GoModule::default();
+    let mut in_require_block = false;
+
+    for raw_line in content.lines() {
+        // 간접 의존성은 기술 스택에서 제외
+        if raw_line.contains("// indirect") {
+            continue;
+        }
+        let line = raw_line.split("//").next().unwrap_or_default().trim();
+        if line.is_empty() {
+            continue;
+        }
+
+        if in_require_block {
+            if line == ")" {
+                in_require_block = false;
+            } else if let Some(dep) = line.split_whitespace().next() {
+                module.requires.push(dep.to_string());
+            }
+            continue;
+        }
+
+        if let Some(rest) = line.strip_prefix("module ") {
+            module.module_path = Some(rest.trim().trim_matches('"').to_string());
+        } else if let Some(rest) = line.strip_prefix("go ") {
+            module.go_version = Some(rest.trim().to_string());
+        } else if let Some(rest) = line.strip_prefix("require") {
+            let rest = rest.trim();
+            if rest == "(" {
+                in_require_block = true;
+            } else if let Some(dep) = rest.split_whitespace().next() {
+                module.requires.push(dep.to_string());
+            }
+        }
+    }
+
+    module
+}
+
+/// go.work의 use 디렉토리 목록 파싱
+fn parse_go_work_uses(content: &str) -> Vec<String> {
+    let mut uses = Vec::new();
+    let mut in_use_block = false;
+
+    for raw_line in content.lines() {
+        let line = raw_line.split("//").next().unwrap_or_default().trim();
+        if line.is_empty() {
+            continue;
+        }
+
+        if in_use_block {
+            if line == ")" {
+                in_use_block = false;
+            } else {
+                uses.push(line.trim_matches('"').to_string());
+            }
+            continue;
+        }
+
+        if let Some(rest) = line.strip_prefix("use") {
+            let rest = rest.trim();
+            if rest == "(" {
+                in_use_block = true;
+            } else if !rest.is_empty() {
+                uses.push(rest.trim_matches('"').to_string());
+            }
+        }
+    }
+
+    uses
+}
+
+/// Go 모듈 경로를 짧은 이름으로 변환 (github.com/go-chi/chi/v5 → chi)
+fn go_dependency_name(module_path: &str) -> String {
+    let segments: Vec<&str> = module_path.split('/').collect();
+    let is_major_suffix =
+        |s: &str| s.len() > 1 && s.starts_with('v') && s[1..].chars().all(|c| c.is_ascii_digit());
+
+    segments
+        .iter()
+        .rev()
+        .find(|s| !is_major_suffix(s))
+        .unwrap_or(&module_path)
+        .to_string()
+}
+
+/// _test.go 파일 존재 여부 (vendor, 숨김 폴더 제외, 깊이 제한)
+fn has_go_test_files(dir: &Path, depth: usize) -> bool {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return false;
+    };
+
+    for entry in entries.flatten() {
+        let file_name = entry.file_name().to_string_lossy().to_string();
+        let path = entry.path();
+
+        if path.is_dir() {
+            if depth > 0
+                && !file_name.starts_with('.')
+                && file_name != "vendor"
+                && file_name != "node_modules"
+                && has_go_test_files(&path, depth - 1)
+            {
+                return true;
+            }
+        } else if file_name.ends_with("_test.go") {
+            return true;
+        }
+    }
+
+    false
+}
+
+/// go.mod / go.work 감지기
+///
+/// 설명은 README가 없을 때만 모듈 경로로 대체되도록 README보다 낮은 우선순위를 가짐
+pub struct GoDetector;
+
+impl ProjectDetector for GoDetector {
+    fn name(&self) -> &str {
+        "go"
+    }
+
+    fn priority(&self) -> i32 {
+        10
+    }
+
+    fn markers(&self) -> Vec<&str> {
+        vec!["go.mod", "go.work"]
+    }
+
+    fn detect(&self, project_dir: &Path) -> Option<Detection> {
+        let go_mod = match read_manifest(project_dir, "go.mod") {
+            Ok(content) => content,
+            Err(message) => return Some(Detection::failed("go.mod", message)),
+        };
+        let go_work_path = project_dir.join("go.work");
+        if go_mod.is_none() && !go_work_path.exists() {
+            return None;
+        }
+
+        let mut detection = Detection {
+            project_types: vec!["go".to_string()],
+            ..Detection::default()
+        };
+        let go_work = detection.read_optional(project_dir, &go_work_path);
+
+        let root_module = go_mod
+            .map(|content| parse_go_mod(&content))
+            .unwrap_or_default();
+        let mut go_version = root_module.go_version;
+        let mut requires = root_module.requires;
+        let mut module_dirs = vec![project_dir.to_path_buf()];
+
+        // go.work: 멀티 모듈 워크스페이스
+        if let Some(content) = go_work {
+            detection.project_types.push("go-workspace".to_string());
+
+            let work = parse_go_mod(&content);
+            if work.go_version.is_some() {
+                go_version = work.go_version;
+            }
+
+            // 루트 모듈(use .)은 하위 패키지로 추가하지 않고, 프로젝트 밖의 모듈은 읽지 않음
+            let root_dir = project_dir
+                .canonicalize()
+                .unwrap_or_else(|_| project_dir.to_path_buf());
+            let mut seen_dirs = vec![root_dir.clone()];
+            let mut internal_modules: Vec<String> =
+                root_module.module_path.iter().cloned().collect();
+            for use_dir in parse_go_work_uses(&content) {
+                let Ok(module_dir) = project_dir.join(&use_dir).canonicalize() else {
+                    continue;
+                };
+                if !module_dir.starts_with(&root_dir) {
+                    detection.warn(
+                        "go.work".to_string(),
+                        format!("Module outside the project folder skipped: {}", use_dir),
+                    );
+                    continue;
+                }
+                if seen_dirs.contains(&module_dir) {
+                    continue;
+                }
+                seen_dirs.push(module_dir.clone());
+
+                let Some(module_content) =
+                    detection.read_optional(&root_dir, &module_dir.join("go.mod"))
+                else {
+                    continue;
+                };
+                let member = parse_go_mod(&module_content);
+                let member_path = member.module_path.unwrap_or_else(|| use_dir.clone());
+
+                requires.extend(member.requires.clone());
+                internal_modules.push(member_path.clone());
+                detection.packages.push(SubPackage {
+                    name: member_path,
+                    path: relative_path(&root_dir, &module_dir),
+                    package_type: "go".to_string(),
+                    dependencies: member.requires,
+                    internal_dependencies: Vec::new(),
+                });
+                module_dirs.push(module_dir);
+            }
+
+            // 워크스페이스 내부 모듈은 기술 스택에서 제외
+            requires.retain(|r| !internal_modules.contains(r));
+        }
+
+        if let Some(version) = go_version {
+            detection.tech_stack.push(format!("go{}", version));
+        }
+        let deps = requires.iter().map(|r| go_dependency_name(r)).collect();
+        extend_unique(&mut detection.tech_stack, deps, 10);
+
+        detection.has_tests = module_dirs.iter().any(|dir| has_go_test_files(dir, 3));
+        detection.description = root_module.module_path;
+
+        Some(detection)
+    }
+}
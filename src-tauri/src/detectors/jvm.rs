@@ -0,0 +1,466 @@
+use super::{
+    all_quoted, declared_version, extend_unique, first_existing, first_quoted, push_unique,
+    read_error_message, read_manifest, read_text, relative_path, Detection, ProjectDetector,
+};
+use crate::scanner::SubPackage;
+use std::path::Path;
+
+/// XML 요소의 자식 요소 중 이름이 일치하는 첫 번째 요소
+fn xml_child<'a, 'input>(
+    node: roxmltree::Node<'a, 'input>,
+    name: &str,
+) -> Option<roxmltree::Node<'a, 'input>> {
+    node.children()
+        .find(|c| c.is_element() && c.tag_name().name() == name)
+}
+
+/// XML 자식 요소의 텍스트
+fn xml_child_text(node: roxmltree::Node, name: &str) -> Option<String> {
+    xml_child(node, name)
+        .and_then(|c| c.text())
+        .map(|t| t.trim().to_string())
+        .filter(|t| !t.is_empty())
+}
+
+/// JVM 의존성/플러그인 좌표로부터 프레임워크 라벨 추론
+fn jvm_framework_labels(coordinates: &[String]) -> Vec<String> {
+    let mut labels = Vec::new();
+    for coordinate in coordinates {
+        if coordinate.contains("org.springframework.boot") || coordinate.contains("spring-boot") {
+            push_unique(&mut labels, "spring-boot".to_string());
+        }
+        if coordinate.contains("org.jetbrains.kotlin") || coordinate.starts_with("kotlin") {
+            push_unique(&mut labels, "kotlin".to_string());
+        }
+        if coordinate.starts_with("com.android.") {
+            push_unique(&mut labels, "android".to_string());
+        }
+    }
+    labels
+}
+
+/// JVM 프레임워크 라벨과 의존성으로 기술 스택 구성
+fn jvm_tech_stack(coordinates: &[String], artifacts: Vec<String>) -> Vec<String> {
+    let mut stack = jvm_framework_labels(coordinates);
+    extend_unique(&mut stack, artifacts, 10);
+    stack
+}
+
+/// pom.xml 파싱 결과
+struct Pom {
+    artifact_id: Option<String>,
+    description: Option<String>,
+    /// <version>, 없으면 <parent>의 버전
+    version: Option<String>,
+    /// groupId:artifactId 좌표 (parent, 의존성, 플러그인)
+    coordinates: Vec<String>,
+    /// 의존성 및 플러그인 artifactId
+    artifacts: Vec<String>,
+    modules: Vec<String>,
+}
+
+/// pom.xml 파싱 (description, 의존성, 플러그인, <modules>)
+fn parse_pom(content: &str) -> Result<Pom, String> {
+    let document =
+        roxmltree::Document::parse(content).map_err(|e| format!("Invalid XML: {}", e))?;
+    let root = document.root_element();
+
+    let mut pom = Pom {
+        artifact_id: xml_child_text(root, "artifactId"),
+        description: xml_child_text(root, "description"),
+        version: xml_child_text(root, "version")
+            .or_else(|| xml_child(root, "parent").and_then(|p| xml_child_text(p, "version"))),
+        coordinates: Vec::new(),
+        artifacts: Vec::new(),
+        modules: Vec::new(),
+    };
+
+    if let Some(parent) = xml_child(root, "parent") {
+        let group = xml_child_text(parent, "groupId").unwrap_or_default();
+        let artifact = xml_child_text(parent, "artifactId").unwrap_or_default();
+        pom.coordinates.push(format!("{}:{}", group, artifact));
+    }
+
+    let dependencies = xml_child(root, "dependencies")
+        .into_iter()
+        .flat_map(|deps| deps.children())
+        .filter(|d| d.is_element() && d.tag_name().name() == "dependency");
+    let plugins = xml_child(root, "build")
+        .and_then(|build| xml_child(build, "plugins"))
+        .into_iter()
+        .flat_map(|plugins| plugins.children())
+        .filter(|p| p.is_element() && p.tag_name().name() == "plugin");
+
+    for node in dependencies.chain(plugins) {
+        let group = xml_child_text(node, "groupId").unwrap_or_default();
+        if let Some(artifact) = xml_child_text(node, "artifactId") {
+            pom.coordinates.push(format!("{}:{}", group, artifact));
+            pom.artifacts.push(artifact);
+        }
+    }
+
+    // 멀티 모듈 (<modules>)
+    if let Some(modules) = xml_child(root, "modules") {
+        for module in modules
+            .children()
+            .filter(|m| m.is_element() && m.tag_name().name() == "module")
+        {
+            if let Some(name) = module.text().map(|t| t.trim()).filter(|t| !t.is_empty()) {
+                push_unique(&mut pom.modules, name.to_string());
+            }
+        }
+    }
+
+    Ok(pom)
+}
+
+/// pom.xml 감지기 (description, 의존성, 플러그인, 멀티 모듈)
+pub struct MavenDetector;
+
+impl ProjectDetector for MavenDetector {
+    fn name(&self) -> &str {
+        "maven"
+    }
+
+    fn priority(&self) -> i32 {
+        70
+    }
+
+    fn markers(&self) -> Vec<&str> {
+        vec!["pom.xml"]
+    }
+
+    fn detect(&self, project_dir: &Path) -> Option<Detection> {
+        let content = match read_manifest(project_dir, "pom.xml") {
+            Ok(Some(content)) => content,
+            Ok(None) => return None,
+            Err(message) => return Some(Detection::failed("pom.xml", message)),
+        };
+        let pom = match parse_pom(&content) {
+            Ok(pom) => pom,
+            Err(message) => return Some(Detection::failed("pom.xml", message)),
+        };
+
+        let mut detection = Detection {
+            project_types: vec!["maven".to_string()],
+            description: pom.description,
+            versions: declared_version("pom.xml", pom.version.as_deref())
+                .into_iter()
+                .collect(),
+            has_tests: project_dir.join("src").join("test").exists(),
+            ..Detection::default()
+        };
+
+        let mut coordinates = pom.coordinates;
+        let mut artifacts = pom.artifacts;
+        let mut module_names = Vec::new();
+        let root_dir = project_dir
+            .canonicalize()
+            .unwrap_or_else(|_| project_dir.to_path_buf());
+
+        for module in pom.modules {
+            let module_dir = project_dir.join(&module);
+            // ../other 처럼 프로젝트 밖을 가리키는 모듈은 읽지 않음
+            if let Ok(real_dir) = module_dir.canonicalize() {
+                if !real_dir.starts_with(&root_dir) {
+                    detection.warn(
+                        "pom.xml".to_string(),
+                        format!("Module outside the project folder skipped: {}", module),
+                    );
+                    continue;
+                }
+            }
+            let module_pom = read_text(&module_dir.join("pom.xml"))
+                .map_err(|e| read_error_message(&e).unwrap_or_else(|| e.to_string()))
+                .and_then(|content| parse_pom(&content));
+            let module_pom = match module_pom {
+                Ok(module_pom) => module_pom,
+                Err(message) => {
+                    detection.warn(
+                        relative_path(project_dir, &module_dir.join("pom.xml")),
+                        message,
+                    );
+                    continue;
+                }
+            };
+
+            let name = module_pom.artifact_id.unwrap_or_else(|| module.clone());
+            detection.has_tests |= module_dir.join("src").join("test").exists();
+            coordinates.extend(module_pom.coordinates);
+            artifacts.extend(module_pom.artifacts.clone());
+
+            module_names.push(name.clone());
+            detection.packages.push(SubPackage {
+                name,
+                path: relative_path(project_dir, &module_dir),
+                package_type: "maven".to_string(),
+                dependencies: module_pom.artifacts,
+                internal_dependencies: Vec::new(),
+            });
+        }
+
+        // 내부 모듈은 기술 스택에서 제외
+        artifacts.retain(|a| !module_names.contains(a));
+        detection.tech_stack = jvm_tech_stack(&coordinates, artifacts);
+
+        Some(detection)
+    }
+}
+
+/// Gradle 의존성 설정 이름
+const GRADLE_CONFIGURATIONS: &[&str] = &[
+    "implementation",
+    "api",
+    "compileOnly",
+    "runtimeOnly",
+    "testImplementation",
+    "testRuntimeOnly",
+    "androidTestImplementation",
+    "debugImplementation",
+    "annotationProcessor",
+    "kapt",
+    "ksp",
+    "classpath",
+];
+
+/// Gradle 빌드 스크립트 파싱 결과
+#[derive(Default)]
+struct GradleBuild {
+    description: Option<String>,
+    version: Option<String>,
+    plugins: Vec<String>,
+    dependencies: Vec<String>,
+    /// project(":core") 형태의 내부 모듈 의존성
+    project_dependencies: Vec<String>,
+}
+
+/// build.gradle / build.gradle.kts 파싱 (plugins, dependencies, description)
+fn parse_gradle_build(content: &str) -> GradleBuild {
+    let mut build = GradleBuild::default();
+
+    for raw_line in content.lines() {
+        let mut line = raw_line.trim();
+        if line.starts_with("//") {
+            continue;
+        }
+
+        // 한 줄 블록: plugins { id '...' }, dependencies { implementation '...' }
+        for block in ["plugins", "dependencies"] {
+            if let Some(rest) = line.strip_prefix(block) {
+                if let Some(inner) = rest.trim_start().strip_prefix('{') {
+                    line = inner.trim_end_matches('}').trim();
+                }
+            }
+        }
+
+        // 플러그인: id("..."), id '...', kotlin("jvm"), apply plugin: '...'
+        if let Some(rest) = line.strip_prefix("id") {
+            if rest.starts_with(['(', ' ', '"', '\'']) {
+                if let Some(id) = first_quoted(rest) {
+                    build.plugins.push(id.to_string());
+                }
+                continue;
+            }
+        }
+        if let Some(rest) = line.strip_prefix("kotlin(") {
+            if let Some(id) = first_quoted(rest) {
+                build.plugins.push(format!("org.jetbrains.kotlin.{}", id));
+            }
+            continue;
+        }
+        if let Some(rest) = line.strip_prefix("apply plugin:") {
+            if let Some(id) = first_quoted(rest) {
+                build.plugins.push(id.to_string());
+            }
+            continue;
+        }
+
+        // 설명: description = "..."
+        if let Some(rest) = line.strip_prefix("description") {
+            if rest.trim_start().starts_with('=') {
+                build.description = first_quoted(rest).map(|d| d.to_string());
+            }
+            continue;
+        }
+
+        // 버전: version = "1.0.0"
+        if let Some(rest) = line.strip_prefix("version") {
+            if rest.trim_start().starts_with('=') {
+                build.version = first_quoted(rest).map(|v| v.to_string());
+            }
+            continue;
+        }
+
+        // 의존성: implementation("group:artifact:version"), implementation(libs.foo.bar)
+        let Some(config) = GRADLE_CONFIGURATIONS.iter().find(|c| {
+            line.strip_prefix(**c)
+                .is_some_and(|rest| rest.starts_with(['(', ' ', '"', '\'']))
+        }) else {
+            continue;
+        };
+        let rest = &line[config.len()..];
+        if let Some(project_start) = rest.find("project(") {
+            if let Some(module) = first_quoted(&rest[project_start..]) {
+                let name = module.trim_start_matches(':').to_string();
+                if !name.is_empty() {
+                    build.project_dependencies.push(name);
+                }
+            }
+        } else if let Some(coordinate) = first_quoted(rest) {
+            build.dependencies.push(coordinate.to_string());
+        } else if let Some(alias_start) = rest.find("libs.") {
+            let alias: String = rest[alias_start + 5..]
+                .chars()
+                .take_while(|c| c.is_alphanumeric() || *c == '.' || *c == '_' || *c == '-')
+                .collect();
+            if !alias.is_empty() {
+                build.dependencies.push(alias.replace('.', "-"));
+            }
+        }
+    }
+
+    build
+}
+
+/// settings.gradle(.kts)의 include 모듈 목록 파싱
+fn parse_gradle_includes(content: &str) -> Vec<String> {
+    let mut modules = Vec::new();
+
+    for raw_line in content.lines() {
+        let line = raw_line.trim();
+        let Some(rest) = line.strip_prefix("include") else {
+            continue;
+        };
+        if !rest.starts_with(['(', ' ', '"', '\'']) {
+            continue;
+        }
+
+        // include(":app", ":core") / include ':app', ':lib'
+        for module in all_quoted(rest) {
+            let name = module.trim_start_matches(':').to_string();
+            if !name.is_empty() {
+                push_unique(&mut modules, name);
+            }
+        }
+    }
+
+    modules
+}
+
+/// gradle.properties의 version 값
+fn gradle_properties_version(content: &str) -> Option<String> {
+    content.lines().find_map(|line| {
+        let (key, value) = line.split_once('=')?;
+        (key.trim() == "version").then(|| value.trim().to_string())
+    })
+}
+
+/// build.gradle(.kts) / settings.gradle(.kts) 감지기 (단일 및 멀티 모듈)
+pub struct GradleDetector;
+
+impl ProjectDetector for GradleDetector {
+    fn name(&self) -> &str {
+        "gradle"
+    }
+
+    fn priority(&self) -> i32 {
+        60
+    }
+
+    fn markers(&self) -> Vec<&str> {
+        vec![
+            "build.gradle.kts",
+            "build.gradle",
+            "settings.gradle.kts",
+            "settings.gradle",
+        ]
+    }
+
+    fn detect(&self, project_dir: &Path) -> Option<Detection> {
+        const BUILD_FILES: &[&str] = &["build.gradle.kts", "build.gradle"];
+        const SETTINGS_FILES: &[&str] = &["settings.gradle.kts", "settings.gradle"];
+
+        let build_file = first_existing(project_dir, BUILD_FILES);
+        let settings_file = first_existing(project_dir, SETTINGS_FILES);
+        if build_file.is_none() && settings_file.is_none() {
+            return None;
+        }
+
+        let mut detection = Detection {
+            project_types: vec!["gradle".to_string()],
+            ..Detection::default()
+        };
+
+        // 루트 프로젝트(이름 없음) + settings.gradle의 include 모듈
+        let mut modules = vec![(None, project_dir.to_path_buf())];
+        if let Some(content) =
+            settings_file.and_then(|path| detection.read_optional(project_dir, &path))
+        {
+            for module in parse_gradle_includes(&content) {
+                let module_dir = project_dir.join(module.replace(':', "/"));
+                modules.push((Some(module), module_dir));
+            }
+        }
+
+        let mut coordinates = Vec::new();
+        let mut artifacts = Vec::new();
+        for (module, dir) in modules {
+            detection.has_tests |= dir.join("src").join("test").exists();
+
+            let build_path = first_existing(&dir, BUILD_FILES);
+            let build = build_path
+                .as_ref()
+                .and_then(|path| detection.read_optional(project_dir, path))
+                .map(|content| parse_gradle_build(&content))
+                .unwrap_or_default();
+
+            if module.is_none() {
+                detection.description = build.description;
+                // 빌드 스크립트에 없으면 gradle.properties의 version=
+                let build_file_name = build_path
+                    .as_ref()
+                    .and_then(|path| path.file_name())
+                    .map(|name| name.to_string_lossy().to_string())
+                    .unwrap_or_default();
+                let version = match build.version {
+                    Some(version) => Some((build_file_name, version)),
+                    None => detection
+                        .read_optional(project_dir, &dir.join("gradle.properties"))
+                        .and_then(|content| gradle_properties_version(&content))
+                        .map(|version| ("gradle.properties".to_string(), version)),
+                };
+                detection.versions = version
+                    .and_then(|(file, version)| declared_version(&file, Some(&version)))
+                    .into_iter()
+                    .collect();
+            }
+
+            coordinates.extend(build.plugins);
+            let mut module_deps = Vec::new();
+            for dependency in build.dependencies {
+                let artifact = dependency
+                    .split(':')
+                    .nth(1)
+                    .unwrap_or(&dependency)
+                    .to_string();
+                module_deps.push(artifact);
+                coordinates.push(dependency);
+            }
+            artifacts.extend(module_deps.clone());
+
+            if let Some(name) = module {
+                module_deps.extend(build.project_dependencies);
+                detection.packages.push(SubPackage {
+                    name,
+                    path: relative_path(project_dir, &dir),
+                    package_type: "gradle".to_string(),
+                    dependencies: module_deps,
+                    internal_dependencies: Vec::new(),
+                });
+            }
+        }
+
+        detection.tech_stack = jvm_tech_stack(&coordinates, artifacts);
+        Some(detection)
+    }
+}
@@ -0,0 +1,497 @@
+use crate::scanner::{DeclaredVersion, Diagnostic, Severity, SubPackage};
+use std::fs;
+use std::io::{self, ErrorKind};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+use toml::Value;
+
+mod generic;
+mod go;
+mod jvm;
+mod node;
+mod python;
+mod rules;
+mod rust;
+
+pub(crate) use rules::get_detection_rules_file;
+pub use rules::load_rule_detectors;
+
+/// 감지기가 반환하는 부분 프로젝트 메타데이터
+#[derive(Debug, Default, Clone)]
+pub struct Detection {
+    pub project_types: Vec<String>,
+    pub description: Option<String>,
+    pub tech_stack: Vec<String>,
+    pub targets: Vec<String>,
+    pub packages: Vec<SubPackage>,
+    pub versions: Vec<DeclaredVersion>,
+    pub has_tests: bool,
+    pub has_ci: bool,
+    pub categories: Vec<String>,
+    pub diagnostics: Vec<Diagnostic>,
+    /// 시간 또는 파일 크기 예산을 넘어 일부만 분석한 경우
+    pub partial: bool,
+}
+
+impl Detection {
+    /// 진단 하나만 담은 결과 (매니페스트를 읽거나 파싱하지 못한 경우)
+    pub(crate) fn failed(file: &str, message: String) -> Self {
+        Detection {
+            diagnostics: vec![Diagnostic {
+                file: file.to_string(),
+                severity: Severity::Error,
+                message,
+            }],
+            ..Detection::default()
+        }
+    }
+
+    /// 경고 진단 추가 (하위 패키지 매니페스트 등 일부 정보만 빠진 경우)
+    pub(crate) fn warn(&mut self, file: String, message: String) {
+        self.diagnostics.push(Diagnostic {
+            file,
+            severity: Severity::Warning,
+            message,
+        });
+    }
+
+    /// 하위 모듈 매니페스트 등 부가 파일 읽기 (없으면 None, 읽을 수 없으면 경고를 남기고 None)
+    ///
+    /// 크기 제한을 넘은 파일은 부분 스캔으로 표시
+    pub(crate) fn read_optional(&mut self, project_dir: &Path, path: &Path) -> Option<String> {
+        match read_text(path) {
+            Ok(content) => Some(content),
+            Err(e) => {
+                if let Some(message) = read_error_message(&e) {
+                    self.partial |= message.starts_with(FILE_TOO_LARGE);
+                    self.warn(relative_path(project_dir, path), message);
+                }
+                None
+            }
+        }
+    }
+
+    /// 다른 감지 결과 병합 (self가 우선순위가 높은 결과, 설명은 detect에서 따로 선택)
+    fn merge(&mut self, other: Detection) {
+        for project_type in other.project_types {
+            push_unique(&mut self.project_types, project_type);
+        }
+        for tech in other.tech_stack {
+            push_unique(&mut self.tech_stack, tech);
+        }
+        for target in other.targets {
+            push_unique(&mut self.targets, target);
+        }
+        for package in other.packages {
+            let exists = self
+                .packages
+                .iter()
+                .any(|p| p.path == package.path && p.package_type == package.package_type);
+            if !exists {
+                self.packages.push(package);
+            }
+        }
+        for version in other.versions {
+            if !self.versions.iter().any(|v| v.source == version.source) {
+                self.versions.push(version);
+            }
+        }
+        self.diagnostics.extend(other.diagnostics);
+        self.partial |= other.partial;
+        self.has_tests |= other.has_tests;
+        self.has_ci |= other.has_ci;
+        for category in other.categories {
+            push_unique(&mut self.categories, category);
+        }
+    }
+}
+
+/// 생태계별 프로젝트 감지기
+pub trait ProjectDetector: Send + Sync {
+    /// 감지기 이름
+    fn name(&self) -> &str;
+
+    /// 병합 우선순위 (높을수록 프로젝트 타입/기술 스택이 앞에 옴)
+    fn priority(&self) -> i32;
+
+    /// 설명 우선순위 (높을수록 이 감지기의 설명을 사용, 기본값은 병합 우선순위)
+    fn description_priority(&self) -> i32 {
+        self.priority()
+    }
+
+    /// 프로젝트 폴더임을 나타내는 파일 (glob 가능, 재귀 스캔 시 사용)
+    fn markers(&self) -> Vec<&str> {
+        Vec::new()
+    }
+
+    /// 프로젝트 디렉토리 분석, 해당하지 않으면 None
+    fn detect(&self, project_dir: &Path) -> Option<Detection>;
+}
+
+/// 감지기와 무관하게 프로젝트 폴더로 취급하는 표식 (.code-tare는 사용자가 직접 만드는 빈 파일)
+const PROJECT_MARKERS: &[&str] = &[".git", ".code-tare"];
+
+/// 감지기 목록과 우선순위 병합
+pub struct DetectorRegistry {
+    detectors: Vec<Box<dyn ProjectDetector>>,
+}
+
+impl DetectorRegistry {
+    /// 빈 레지스트리
+    pub fn new() -> Self {
+        DetectorRegistry {
+            detectors: Vec::new(),
+        }
+    }
+
+    /// 내장 감지기가 등록된 레지스트리
+    pub fn with_builtin() -> Self {
+        let mut registry = DetectorRegistry::new();
+        registry.register(Box::new(node::NodeDetector));
+        registry.register(Box::new(python::PyprojectDetector));
+        registry.register(Box::new(rust::CargoDetector));
+        registry.register(Box::new(jvm::MavenDetector));
+        registry.register(Box::new(jvm::GradleDetector));
+        registry.register(Box::new(python::SetuptoolsDetector));
+        registry.register(Box::new(python::PipenvDetector));
+        registry.register(Box::new(python::RequirementsDetector));
+        registry.register(Box::new(generic::ReadmeDetector));
+        registry.register(Box::new(go::GoDetector));
+        registry.register(Box::new(generic::LayoutDetector));
+        registry
+    }
+
+    /// 감지기 등록 (우선순위 내림차순 유지, 같은 우선순위는 등록 순서)
+    pub fn register(&mut self, detector: Box<dyn ProjectDetector>) {
+        let index = self
+            .detectors
+            .iter()
+            .position(|d| d.priority() < detector.priority())
+            .unwrap_or(self.detectors.len());
+        self.detectors.insert(index, detector);
+    }
+
+    /// 등록된 감지기 이름 (우선순위 순)
+    pub fn detector_names(&self) -> Vec<&str> {
+        self.detectors.iter().map(|d| d.name()).collect()
+    }
+
+    /// 프로젝트 표식이 있는 폴더인지 확인
+    pub fn is_project_dir(&self, dir: &Path) -> bool {
+        PROJECT_MARKERS
+            .iter()
+            .any(|marker| dir.join(marker).exists())
+            || self
+                .detectors
+                .iter()
+                .flat_map(|d| d.markers())
+                .any(|marker| marker_exists(dir, marker))
+    }
+
+    /// 모든 감지기 실행 후 우선순위 순으로 병합, 감지기별 실행 시간 함께 반환
+    ///
+    /// deadline이 지나면 남은 감지기는 실행하지 않고 부분 스캔으로 표시
+    pub fn detect(&self, project_dir: &Path, deadline: Instant) -> (Detection, Vec<DetectorRun<'_>>) {
+        let mut merged = Detection::default();
+        let mut runs = Vec::with_capacity(self.detectors.len());
+        let mut skipped = Vec::new();
+        let mut description: Option<(i32, String)> = None;
+        for detector in &self.detectors {
+            let started = Instant::now();
+            if started >= deadline {
+                skipped.push(detector.name());
+                continue;
+            }
+            let detection = detector.detect(project_dir);
+            runs.push(DetectorRun {
+                name: detector.name(),
+                elapsed: started.elapsed(),
+                matched: detection.is_some(),
+            });
+            if let Some(mut detection) = detection {
+                if let Some(text) = detection.description.take() {
+                    let priority = detector.description_priority();
+                    if !matches!(&description, Some((best, _)) if *best >= priority) {
+                        description = Some((priority, text));
+                    }
+                }
+                merged.merge(detection);
+            }
+        }
+        merged.description = description.map(|(_, text)| text);
+        link_internal_dependencies(&mut merged.packages);
+
+        if !skipped.is_empty() {
+            merged.partial = true;
+            merged.warn(
+                String::new(),
+                format!(
+                    "Scan time budget exceeded, skipped detectors: {}",
+                    skipped.join(", ")
+                ),
+            );
+        }
+        if merged
+            .diagnostics
+            .iter()
+            .any(|d| d.message.starts_with(FILE_TOO_LARGE))
+        {
+            merged.partial = true;
+        }
+        (merged, runs)
+    }
+}
+
+/// 프로젝트 하나에 대한 감지기 한 번의 실행 결과
+pub struct DetectorRun<'a> {
+    pub name: &'a str,
+    pub elapsed: Duration,
+    /// 감지기가 Detection을 반환했는지 여부
+    pub matched: bool,
+}
+
+/// 폴더 안에 표식 파일이 있는지 확인 (glob 패턴 지원)
+fn marker_exists(dir: &Path, marker: &str) -> bool {
+    if !marker.contains(['*', '?', '[']) {
+        return dir.join(marker).exists();
+    }
+
+    let base = glob::Pattern::escape(&dir.to_string_lossy());
+    let pattern = format!("{}/{}", base, marker.trim_start_matches('/'));
+    glob::glob(&pattern)
+        .map(|mut paths| paths.any(|p| p.is_ok()))
+        .unwrap_or(false)
+}
+
+/// 하위 패키지 간 내부 의존성 연결
+fn link_internal_dependencies(packages: &mut [SubPackage]) {
+    let names: Vec<String> = packages.iter().map(|p| p.name.clone()).collect();
+    for package in packages.iter_mut() {
+        package.internal_dependencies = package
+            .dependencies
+            .iter()
+            .filter(|dep| **dep != package.name && names.contains(dep))
+            .cloned()
+            .collect();
+    }
+}
+
+/// 매니페스트 버전 (빈 값, 변수 참조 제외)
+pub(crate) fn declared_version(source: &str, version: Option<&str>) -> Option<DeclaredVersion> {
+    let version = version?.trim();
+    if version.is_empty() || version.starts_with("${") {
+        return None;
+    }
+    Some(DeclaredVersion {
+        source: source.to_string(),
+        version: version.to_string(),
+    })
+}
+
+/// 중복 없이 목록에 추가
+pub(crate) fn push_unique(list: &mut Vec<String>, item: String) {
+    if !list.contains(&item) {
+        list.push(item);
+    }
+}
+
+/// 중복 제거 후 최대 개수만큼 추가
+pub(crate) fn extend_unique(list: &mut Vec<String>, items: Vec<String>, limit: usize) {
+    let mut unique = Vec::new();
+    for item in items {
+        if !list.contains(&item) {
+            push_unique(&mut unique, item);
+        }
+    }
+    list.extend(unique.into_iter().take(limit));
+}
+
+/// TOML 테이블의 키 목록 추출
+pub(crate) fn toml_table_keys(value: Option<&Value>) -> Vec<String> {
+    value
+        .and_then(|v| v.as_table())
+        .map(|t| t.keys().cloned().collect())
+        .unwrap_or_default()
+}
+
+/// 문자열에서 첫 번째 따옴표 안의 내용과 닫는 따옴표 다음 위치 (백슬래시 이스케이프 건너뜀)
+fn quoted_span(text: &str) -> Option<(&str, usize)> {
+    let start = text.find(['"', '\''])?;
+    let quote = text.as_bytes()[start];
+    let mut escaped = false;
+    for (offset, byte) in text.bytes().enumerate().skip(start + 1) {
+        if escaped {
+            escaped = false;
+        } else if byte == b'\\' {
+            escaped = true;
+        } else if byte == quote {
+            return Some((&text[start + 1..offset], offset + 1));
+        }
+    }
+    None
+}
+
+/// 문자열에서 첫 번째 따옴표 안의 내용 추출
+pub(crate) fn first_quoted(text: &str) -> Option<&str> {
+    quoted_span(text).map(|(item, _)| item)
+}
+
+/// 문자열에서 따옴표로 감싼 모든 값 추출
+pub(crate) fn all_quoted(text: &str) -> Vec<String> {
+    let mut items = Vec::new();
+    let mut remaining = text;
+    while let Some((item, end)) = quoted_span(remaining) {
+        items.push(item.to_string());
+        remaining = &remaining[end..];
+    }
+    items
+}
+
+/// 감지기가 읽는 파일의 최대 크기 (넘으면 읽지 않고 진단으로 표시)
+pub(crate) const MAX_FILE_SIZE: u64 = 4 * 1024 * 1024;
+
+/// 크기 제한을 넘은 파일의 진단 메시지 앞부분
+const FILE_TOO_LARGE: &str = "File is too large to scan";
+
+fn format_megabytes(bytes: u64) -> String {
+    format!("{:.1} MB", bytes as f64 / (1024.0 * 1024.0))
+}
+
+/// 텍스트 파일 읽기 (MAX_FILE_SIZE를 넘으면 읽지 않고 InvalidInput 오류)
+pub(crate) fn read_text(path: &Path) -> io::Result<String> {
+    let size = fs::metadata(path)?.len();
+    if size > MAX_FILE_SIZE {
+        return Err(io::Error::new(
+            ErrorKind::InvalidInput,
+            format!(
+                "{} ({}, limit {})",
+                FILE_TOO_LARGE,
+                format_megabytes(size),
+                format_megabytes(MAX_FILE_SIZE)
+            ),
+        ));
+    }
+    fs::read_to_string(path)
+}
+
+/// 파일 읽기 오류를 진단 메시지로 변환 (파일이 없으면 None)
+pub(crate) fn read_error_message(error: &io::Error) -> Option<String> {
+    match error.kind() {
+        ErrorKind::NotFound => None,
+        ErrorKind::InvalidData => Some("File is not valid UTF-8".to_string()),
+        ErrorKind::InvalidInput => Some(error.to_string()),
+        _ => Some(format!("Failed to read file: {}", error)),
+    }
+}
+
+/// TOML 파싱 오류를 한 줄 메시지로 변환 ("Invalid TOML at line 3: invalid table header")
+pub(crate) fn toml_error_message(content: &str, error: &toml::de::Error) -> String {
+    let message = error.message().lines().next().unwrap_or_default();
+    match error.span() {
+        Some(span) => {
+            let line = content[..span.start.min(content.len())]
+                .matches('\n')
+                .count()
+                + 1;
+            format!("Invalid TOML at line {}: {}", line, message)
+        }
+        None => format!("Invalid TOML: {}", message),
+    }
+}
+
+/// 프로젝트 폴더의 매니페스트 읽기 (없으면 Ok(None), 읽을 수 없으면 오류 메시지)
+pub(crate) fn read_manifest(project_dir: &Path, file: &str) -> Result<Option<String>, String> {
+    match read_text(&project_dir.join(file)) {
+        Ok(content) => Ok(Some(content)),
+        Err(e) => read_error_message(&e).map_or(Ok(None), Err),
+    }
+}
+
+/// 첫 번째로 존재하는 파일 경로
+pub(crate) fn first_existing(dir: &Path, candidates: &[&str]) -> Option<PathBuf> {
+    candidates
+        .iter()
+        .map(|name| dir.join(name))
+        .find(|path| path.exists())
+}
+
+/// 프로젝트 폴더 기준 상대 경로 ('/' 구분)
+pub(crate) fn relative_path(root: &Path, path: &Path) -> String {
+    path.strip_prefix(root)
+        .unwrap_or(path)
+        .components()
+        .map(|c| c.as_os_str().to_string_lossy().to_string())
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+/// 워크스페이스 glob 패턴을 매니페스트가 있는 하위 폴더 목록으로 확장 ("!" 접두어는 제외 패턴)
+pub(crate) fn expand_package_globs(
+    root: &Path,
+    patterns: &[String],
+    manifest: &str,
+) -> Vec<PathBuf> {
+    let base = glob::Pattern::escape(&root.to_string_lossy());
+    let full_pattern = |pattern: &str| {
+        let pattern = pattern
+            .trim()
+            .trim_start_matches("./")
+            .trim_end_matches('/');
+        format!("{}/{}", base, pattern)
+    };
+
+    let excluded: Vec<glob::Pattern> = patterns
+        .iter()
+        .filter_map(|p| p.strip_prefix('!'))
+        .filter_map(|p| glob::Pattern::new(&full_pattern(p)).ok())
+        .collect();
+
+    let mut packages = Vec::new();
+    for pattern in patterns.iter().filter(|p| !p.starts_with('!')) {
+        let Ok(paths) = glob::glob(&full_pattern(pattern)) else {
+            continue;
+        };
+        for path in paths.flatten() {
+            let in_node_modules = path
+                .strip_prefix(root)
+                .map(|rel| rel.components().any(|c| c.as_os_str() == "node_modules"))
+                .unwrap_or(false);
+            if path.join(manifest).is_file()
+                && !in_node_modules
+                && !excluded.iter().any(|e| e.matches_path(&path))
+                && !packages.contains(&path)
+            {
+                packages.push(path);
+            }
+        }
+    }
+    packages
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn all_quoted_keeps_empty_items() {
+        assert_eq!(all_quoted(r#"("", ":app")"#), vec!["", ":app"]);
+    }
+
+    #[test]
+    fn all_quoted_does_not_rescan_repeated_substrings() {
+        assert_eq!(all_quoted(r#"id "i""#), vec!["i"]);
+        assert_eq!(all_quoted("include ':a', ':a'"), vec![":a", ":a"]);
+    }
+
+    #[test]
+    fn all_quoted_skips_escaped_quotes() {
+        assert_eq!(all_quoted(r#"["a\"b", 'c']"#), vec![r#"a\"b"#, "c"]);
+        assert_eq!(all_quoted(r#"'it\'s' "x""#), vec![r#"it\'s"#, "x"]);
+    }
+
+    #[test]
+    fn first_quoted_requires_closing_quote() {
+        assert_eq!(first_quoted(r#"version = "1.0"#), None);
+        assert_eq!(first_quoted(r#"version = '1.0' # "x""#), Some("1.0"));
+    }
+}
@@ -0,0 +1,257 @@
+use super::{
+    declared_version, expand_package_globs, extend_unique, push_unique, read_error_message,
+    read_manifest, read_text, relative_path, Detection, ProjectDetector,
+};
+use crate::scanner::SubPackage;
+use std::path::Path;
+
+/// package.json의 의존성 이름 목록 (dependencies, devDependencies, peerDependencies)
+fn package_dependencies(pkg: &serde_json::Value, include_peer: bool) -> Vec<String> {
+    let mut keys = vec!["dependencies", "devDependencies"];
+    if include_peer {
+        keys.push("peerDependencies");
+    }
+
+    let mut deps = Vec::new();
+    for key in keys {
+        if let Some(table) = pkg.get(key).and_then(|v| v.as_object()) {
+            deps.extend(table.keys().cloned());
+        }
+    }
+    deps
+}
+
+/// JSON 문자열 배열 추출
+fn json_string_list(value: Option<&serde_json::Value>) -> Vec<String> {
+    value
+        .and_then(|v| v.as_array())
+        .map(|arr| {
+            arr.iter()
+                .filter_map(|v| v.as_str())
+                .map(|s| s.to_string())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// pnpm-workspace.yaml의 packages 목록 파싱
+fn parse_pnpm_workspace(content: &str) -> Vec<String> {
+    let mut patterns = Vec::new();
+    let mut in_packages = false;
+
+    for line in content.lines() {
+        let trimmed = line.trim();
+        if trimmed.is_empty() || trimmed.starts_with('#') {
+            continue;
+        }
+
+        if !line.starts_with([' ', '\t', '-']) {
+            in_packages = trimmed.starts_with("packages:");
+            continue;
+        }
+
+        if in_packages {
+            if let Some(item) = trimmed.strip_prefix('-') {
+                let item = item.split(" #").next().unwrap_or_default().trim();
+                let item = item.trim_matches(|c| c == '"' || c == '\'');
+                if !item.is_empty() {
+                    patterns.push(item.to_string());
+                }
+            }
+        }
+    }
+
+    patterns
+}
+
+/// 모노레포 도구 및 워크스페이스 패턴 수집 (읽지 못한 설정 파일은 경고로 기록)
+fn workspace_layout(
+    project_dir: &Path,
+    pkg: &serde_json::Value,
+    detection: &mut Detection,
+) -> (Vec<String>, Vec<String>) {
+    let mut tools = Vec::new();
+    let mut patterns = Vec::new();
+
+    // npm/yarn workspaces: 배열 또는 { packages: [...] }
+    if let Some(workspaces) = pkg.get("workspaces") {
+        let list = if workspaces.is_array() {
+            json_string_list(Some(workspaces))
+        } else {
+            json_string_list(workspaces.get("packages"))
+        };
+        if !list.is_empty() {
+            let tool = if project_dir.join("yarn.lock").exists() {
+                "yarn-workspaces"
+            } else {
+                "npm-workspaces"
+            };
+            tools.push(tool.to_string());
+            patterns.extend(list);
+        }
+    }
+
+    if let Some(content) =
+        detection.read_optional(project_dir, &project_dir.join("pnpm-workspace.yaml"))
+    {
+        tools.push("pnpm-workspace".to_string());
+        patterns.extend(parse_pnpm_workspace(&content));
+    }
+
+    if let Some(content) = detection.read_optional(project_dir, &project_dir.join("lerna.json")) {
+        tools.push("lerna".to_string());
+        let lerna = serde_json::from_str::<serde_json::Value>(&content).unwrap_or_else(|e| {
+            detection.warn("lerna.json".to_string(), format!("Invalid JSON: {}", e));
+            serde_json::Value::Null
+        });
+        let lerna_packages = json_string_list(lerna.get("packages"));
+        if !lerna_packages.is_empty() {
+            patterns.extend(lerna_packages);
+        } else if patterns.is_empty() {
+            patterns.push("packages/*".to_string());
+        }
+    }
+
+    if project_dir.join("nx.json").exists() {
+        tools.push("nx".to_string());
+        if patterns.is_empty() {
+            patterns.extend(["apps/*", "libs/*", "packages/*"].map(|p| p.to_string()));
+        }
+    }
+
+    if project_dir.join("turbo.json").exists() {
+        tools.push("turborepo".to_string());
+    }
+
+    (tools, patterns)
+}
+
+/// 하위 패키지 정보 (package.json 우선, 없으면 Nx project.json)
+///
+/// 매니페스트를 읽거나 파싱하지 못하면 (파일 경로, 오류 메시지)
+fn read_sub_package(
+    project_dir: &Path,
+    package_dir: &Path,
+) -> Result<SubPackage, (String, String)> {
+    let dir_name = package_dir
+        .file_name()
+        .unwrap_or_default()
+        .to_string_lossy()
+        .to_string();
+
+    let manifest = if package_dir.join("package.json").exists() {
+        "package.json"
+    } else {
+        "project.json"
+    };
+    let manifest_path = relative_path(project_dir, &package_dir.join(manifest));
+    let value = read_text(&package_dir.join(manifest))
+        .map_err(|e| read_error_message(&e).unwrap_or_else(|| e.to_string()))
+        .and_then(|content| {
+            serde_json::from_str::<serde_json::Value>(&content)
+                .map_err(|e| format!("Invalid JSON: {}", e))
+        })
+        .map_err(|message| (manifest_path, message))?;
+
+    let name = value
+        .get("name")
+        .and_then(|n| n.as_str())
+        .map(|s| s.to_string());
+    let dependencies = if manifest == "package.json" {
+        package_dependencies(&value, true)
+    } else {
+        json_string_list(value.get("implicitDependencies"))
+    };
+
+    Ok(SubPackage {
+        name: name.unwrap_or(dir_name),
+        path: relative_path(project_dir, package_dir),
+        package_type: "nodejs".to_string(),
+        dependencies,
+        internal_dependencies: Vec::new(),
+    })
+}
+
+/// package.json 기반 Node.js 감지기 (npm/yarn/pnpm 워크스페이스, Nx, Turborepo, Lerna 포함)
+pub struct NodeDetector;
+
+impl ProjectDetector for NodeDetector {
+    fn name(&self) -> &str {
+        "node"
+    }
+
+    fn priority(&self) -> i32 {
+        100
+    }
+
+    /// package.json 설명은 다른 매니페스트 설명이 없을 때만 사용 (README보다는 우선)
+    fn description_priority(&self) -> i32 {
+        25
+    }
+
+    fn markers(&self) -> Vec<&str> {
+        vec!["package.json"]
+    }
+
+    fn detect(&self, project_dir: &Path) -> Option<Detection> {
+        let content = match read_manifest(project_dir, "package.json") {
+            Ok(Some(content)) => content,
+            Ok(None) => return None,
+            Err(message) => return Some(Detection::failed("package.json", message)),
+        };
+        let pkg = match serde_json::from_str::<serde_json::Value>(&content) {
+            Ok(pkg) => pkg,
+            Err(e) => {
+                return Some(Detection::failed(
+                    "package.json",
+                    format!("Invalid JSON: {}", e),
+                ))
+            }
+        };
+
+        let mut detection = Detection {
+            project_types: vec!["nodejs".to_string()],
+            description: pkg
+                .get("description")
+                .and_then(|v| v.as_str())
+                .map(|s| s.to_string()),
+            versions: declared_version("package.json", pkg.get("version").and_then(|v| v.as_str()))
+                .into_iter()
+                .collect(),
+            ..Detection::default()
+        };
+
+        // 테스트 확인
+        if let Some(scripts) = pkg.get("scripts").and_then(|v| v.as_object()) {
+            detection.has_tests = scripts.contains_key("test") || scripts.contains_key("test:unit");
+        }
+
+        // 모노레포 하위 패키지
+        let (tools, patterns) = workspace_layout(project_dir, &pkg, &mut detection);
+        for tool in tools {
+            push_unique(&mut detection.project_types, tool);
+        }
+        let mut package_dirs = expand_package_globs(project_dir, &patterns, "package.json");
+        for dir in expand_package_globs(project_dir, &patterns, "project.json") {
+            if !package_dirs.contains(&dir) {
+                package_dirs.push(dir);
+            }
+        }
+        for package_dir in package_dirs {
+            match read_sub_package(project_dir, &package_dir) {
+                Ok(package) => detection.packages.push(package),
+                Err((file, message)) => detection.warn(file, message),
+            }
+        }
+
+        // 기술 스택 추출 (내부 패키지 제외)
+        let internal: Vec<&String> = detection.packages.iter().map(|p| &p.name).collect();
+        let deps = package_dependencies(&pkg, false)
+            .into_iter()
+            .filter(|dep| !internal.contains(&dep))
+            .collect();
+        extend_unique(&mut detection.tech_stack, deps, 10);
+
+        Some(detection)
+    }
+}
@@ -0,0 +1,417 @@
+use super::{
+    all_quoted, declared_version, extend_unique, first_quoted, read_manifest, toml_error_message,
+    toml_table_keys, Detection, ProjectDetector,
+};
+use std::path::Path;
+use toml::Value;
+
+/// PEP 508 의존성 문자열에서 패키지 이름 추출 ("requests[socks]>=2.0" → requests)
+fn pep508_name(spec: &str) -> Option<String> {
+    let name: String = spec
+        .trim()
+        .chars()
+        .take_while(|c| c.is_alphanumeric() || matches!(c, '-' | '_' | '.'))
+        .collect();
+    if name.is_empty() {
+        None
+    } else {
+        Some(name)
+    }
+}
+
+/// 문자열 배열 형태의 PEP 508 의존성 목록 추출
+fn pep508_names(value: Option<&Value>) -> Vec<String> {
+    value
+        .and_then(|v| v.as_array())
+        .map(|arr| {
+            arr.iter()
+                .filter_map(|v| v.as_str())
+                .filter_map(pep508_name)
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Python 의존성 목록으로 감지 결과 생성 (python 자체 제외, 최대 10개)
+fn python_detection(
+    project_type: String,
+    description: Option<String>,
+    deps: Vec<String>,
+) -> Detection {
+    let mut detection = Detection {
+        project_types: vec![project_type],
+        description: description.filter(|d| !d.trim().is_empty()),
+        ..Detection::default()
+    };
+    let deps = deps
+        .into_iter()
+        .filter(|dep| !dep.eq_ignore_ascii_case("python"))
+        .collect();
+    extend_unique(&mut detection.tech_stack, deps, 10);
+    detection
+}
+
+/// pyproject.toml의 빌드 백엔드/패키지 관리 도구 판별
+fn python_build_backend(project_path: &Path, config: &Value) -> &'static str {
+    let tool = config.get("tool");
+    let has_tool = |name: &str| tool.and_then(|t| t.get(name)).is_some();
+
+    // [build-system].build-backend가 명시된 경우 우선
+    if let Some(backend) = config
+        .get("build-system")
+        .and_then(|b| b.get("build-backend"))
+        .and_then(|b| b.as_str())
+    {
+        if backend.starts_with("poetry") {
+            return "poetry";
+        }
+        if backend.starts_with("hatchling") {
+            return "hatch";
+        }
+        if backend.starts_with("pdm") {
+            return "pdm";
+        }
+        if backend.starts_with("flit") {
+            return "flit";
+        }
+        if backend.starts_with("uv_build") {
+            return "uv";
+        }
+        if backend.starts_with("maturin") {
+            return "maturin";
+        }
+        if backend.starts_with("setuptools") {
+            return "setuptools";
+        }
+    }
+
+    // 백엔드가 없으면 도구 설정 및 잠금 파일로 추론
+    if has_tool("poetry") || project_path.join("poetry.lock").exists() {
+        "poetry"
+    } else if has_tool("uv") || project_path.join("uv.lock").exists() {
+        "uv"
+    } else if has_tool("pdm") || project_path.join("pdm.lock").exists() {
+        "pdm"
+    } else if has_tool("hatch") {
+        "hatch"
+    } else if has_tool("flit") {
+        "flit"
+    } else {
+        // PEP 517 기본 백엔드
+        "setuptools"
+    }
+}
+
+/// pyproject.toml 감지기 ([project] 테이블 및 [tool.poetry])
+pub struct PyprojectDetector;
+
+impl ProjectDetector for PyprojectDetector {
+    fn name(&self) -> &str {
+        "pyproject"
+    }
+
+    fn priority(&self) -> i32 {
+        90
+    }
+
+    fn markers(&self) -> Vec<&str> {
+        vec!["pyproject.toml"]
+    }
+
+    fn detect(&self, project_dir: &Path) -> Option<Detection> {
+        let content = match read_manifest(project_dir, "pyproject.toml") {
+            Ok(Some(content)) => content,
+            Ok(None) => return None,
+            Err(message) => return Some(Detection::failed("pyproject.toml", message)),
+        };
+        let config = match content.parse::<Value>() {
+            Ok(config) => config,
+            Err(e) => {
+                return Some(Detection::failed(
+                    "pyproject.toml",
+                    toml_error_message(&content, &e),
+                ))
+            }
+        };
+
+        let backend = python_build_backend(project_dir, &config);
+        let pep621 = config.get("project");
+        let poetry = config.get("tool").and_then(|t| t.get("poetry"));
+
+        let description = pep621
+            .and_then(|p| p.get("description"))
+            .or_else(|| poetry.and_then(|p| p.get("description")))
+            .and_then(|d| d.as_str())
+            .map(|d| d.to_string());
+
+        // 의존성 추출: [project].dependencies, optional-dependencies, [tool.poetry]
+        let mut deps = pep508_names(pep621.and_then(|p| p.get("dependencies")));
+        if let Some(optional) = pep621
+            .and_then(|p| p.get("optional-dependencies"))
+            .and_then(|o| o.as_table())
+        {
+            for group in optional.values() {
+                deps.extend(pep508_names(Some(group)));
+            }
+        }
+        deps.extend(toml_table_keys(poetry.and_then(|p| p.get("dependencies"))));
+
+        // 버전: [project].version (dynamic이면 없음) 또는 [tool.poetry].version
+        let version = pep621
+            .and_then(|p| p.get("version"))
+            .or_else(|| poetry.and_then(|p| p.get("version")))
+            .and_then(|v| v.as_str());
+
+        let mut detection = python_detection(format!("python-{}", backend), description, deps);
+        detection.versions = declared_version("pyproject.toml", version)
+            .into_iter()
+            .collect();
+        Some(detection)
+    }
+}
+
+/// setup.cfg의 섹션 내 키 값 (여러 줄 값 포함)
+fn setup_cfg_value(content: &str, section: &str, key: &str) -> Option<String> {
+    let mut in_section = false;
+    let mut value: Option<String> = None;
+
+    for line in content.lines() {
+        let trimmed = line.trim();
+        if trimmed.starts_with('[') {
+            if value.is_some() {
+                break;
+            }
+            in_section = trimmed == format!("[{}]", section);
+            continue;
+        }
+        if !in_section || trimmed.starts_with('#') || trimmed.starts_with(';') {
+            continue;
+        }
+
+        // 들여쓰기된 줄은 이전 키의 연속 값
+        if let Some(current) = value.as_mut() {
+            if line.starts_with([' ', '\t']) || trimmed.is_empty() {
+                current.push('\n');
+                current.push_str(trimmed);
+                continue;
+            }
+            break;
+        }
+
+        if let Some((k, v)) = trimmed.split_once('=') {
+            if k.trim() == key {
+                value = Some(v.trim().to_string());
+            }
+        }
+    }
+
+    value.map(|v| v.trim().to_string())
+}
+
+/// setup.py에서 키워드 인자 값의 시작 부분 (key= 뒤)
+///
+/// long_description=처럼 다른 이름의 일부로 나온 경우는 건너뜀
+fn setup_py_argument<'a>(content: &'a str, key: &str) -> Option<&'a str> {
+    content.match_indices(key).find_map(|(start, _)| {
+        let at_boundary = content[..start]
+            .chars()
+            .next_back()
+            .map_or(true, |c| c == '(' || c == ',' || c.is_whitespace());
+        if !at_boundary {
+            return None;
+        }
+        let rest = content[start + key.len()..].trim_start();
+        let value = rest.strip_prefix('=')?;
+        // 비교 연산자(==)는 키워드 인자가 아님
+        if value.starts_with('=') {
+            return None;
+        }
+        Some(value.trim_start())
+    })
+}
+
+/// 여는 괄호 바로 뒤 텍스트에서 짝이 맞는 닫는 괄호 위치 (따옴표 안의 괄호는 무시)
+fn closing_bracket(text: &str) -> Option<usize> {
+    let mut depth = 0;
+    let mut quote = None;
+    let mut escaped = false;
+    for (i, c) in text.char_indices() {
+        match quote {
+            Some(q) => {
+                if escaped {
+                    escaped = false;
+                } else if c == '\\' {
+                    escaped = true;
+                } else if c == q {
+                    quote = None;
+                }
+            }
+            None => match c {
+                '"' | '\'' => quote = Some(c),
+                '[' => depth += 1,
+                ']' if depth == 0 => return Some(i),
+                ']' => depth -= 1,
+                _ => {}
+            },
+        }
+    }
+    None
+}
+
+/// setup.py에서 키워드 인자의 문자열 값 추출 (description="...")
+fn setup_py_string(content: &str, key: &str) -> Option<String> {
+    let rest = setup_py_argument(content, key)?;
+    if !rest.starts_with(['"', '\'']) {
+        return None;
+    }
+    first_quoted(rest).map(|s| s.to_string())
+}
+
+/// setup.py에서 리스트 키워드 인자의 문자열 목록 추출 (install_requires=[...])
+fn setup_py_list(content: &str, key: &str) -> Vec<String> {
+    setup_py_argument(content, key)
+        .and_then(|rest| rest.strip_prefix('['))
+        .and_then(|r| closing_bracket(r).map(|end| all_quoted(&r[..end])))
+        .unwrap_or_default()
+}
+
+/// setup.cfg / setup.py 감지기 (Python setuptools)
+pub struct SetuptoolsDetector;
+
+impl ProjectDetector for SetuptoolsDetector {
+    fn name(&self) -> &str {
+        "setuptools"
+    }
+
+    fn priority(&self) -> i32 {
+        50
+    }
+
+    fn markers(&self) -> Vec<&str> {
+        vec!["setup.py", "setup.cfg"]
+    }
+
+    fn detect(&self, project_dir: &Path) -> Option<Detection> {
+        let setup_cfg_path = project_dir.join("setup.cfg");
+        let setup_py_path = project_dir.join("setup.py");
+        if !setup_cfg_path.exists() && !setup_py_path.exists() {
+            return None;
+        }
+
+        // 읽지 못한 파일은 경고로 남기고 나머지 파일로 분석
+        let mut diagnostics = Detection::default();
+        let setup_cfg = diagnostics.read_optional(project_dir, &setup_cfg_path);
+        let setup_py = diagnostics.read_optional(project_dir, &setup_py_path);
+
+        let mut description = None;
+        let mut version = None;
+        let mut deps = Vec::new();
+
+        if let Some(content) = &setup_cfg {
+            description = setup_cfg_value(content, "metadata", "description");
+            // "attr: pkg.__version__", "file: VERSION" 같은 지시자는 값이 아님
+            version = setup_cfg_value(content, "metadata", "version")
+                .filter(|v| !v.starts_with("attr:") && !v.starts_with("file:"))
+                .and_then(|v| declared_version("setup.cfg", Some(&v)));
+            if let Some(requires) = setup_cfg_value(content, "options", "install_requires") {
+                deps.extend(requires.split(['\n', ',']).filter_map(pep508_name));
+            }
+        }
+        if let Some(content) = &setup_py {
+            if description.is_none() {
+                description = setup_py_string(content, "description");
+            }
+            if version.is_none() {
+                let setup_py_version = setup_py_string(content, "version");
+                version = declared_version("setup.py", setup_py_version.as_deref());
+            }
+            deps.extend(
+                setup_py_list(content, "install_requires")
+                    .iter()
+                    .filter_map(|spec| pep508_name(spec)),
+            );
+        }
+
+        let mut detection = python_detection("python-setuptools".to_string(), description, deps);
+        detection.versions = version.into_iter().collect();
+        detection.diagnostics = diagnostics.diagnostics;
+        Some(detection)
+    }
+}
+
+/// Pipfile 감지기 (Python Pipenv)
+pub struct PipenvDetector;
+
+impl ProjectDetector for PipenvDetector {
+    fn name(&self) -> &str {
+        "pipenv"
+    }
+
+    fn priority(&self) -> i32 {
+        40
+    }
+
+    fn markers(&self) -> Vec<&str> {
+        vec!["Pipfile"]
+    }
+
+    fn detect(&self, project_dir: &Path) -> Option<Detection> {
+        let content = match read_manifest(project_dir, "Pipfile") {
+            Ok(Some(content)) => content,
+            Ok(None) => return None,
+            Err(message) => return Some(Detection::failed("Pipfile", message)),
+        };
+        let pipfile = match content.parse::<Value>() {
+            Ok(pipfile) => pipfile,
+            Err(e) => {
+                return Some(Detection::failed(
+                    "Pipfile",
+                    toml_error_message(&content, &e),
+                ))
+            }
+        };
+
+        let mut deps = toml_table_keys(pipfile.get("packages"));
+        deps.extend(toml_table_keys(pipfile.get("dev-packages")));
+
+        Some(python_detection("python-pipenv".to_string(), None, deps))
+    }
+}
+
+/// requirements.txt 감지기 (Python pip)
+pub struct RequirementsDetector;
+
+impl ProjectDetector for RequirementsDetector {
+    fn name(&self) -> &str {
+        "requirements"
+    }
+
+    fn priority(&self) -> i32 {
+        30
+    }
+
+    fn markers(&self) -> Vec<&str> {
+        vec!["requirements.txt"]
+    }
+
+    fn detect(&self, project_dir: &Path) -> Option<Detection> {
+        let content = match read_manifest(project_dir, "requirements.txt") {
+            Ok(Some(content)) => content,
+            Ok(None) => return None,
+            Err(message) => return Some(Detection::failed("requirements.txt", message)),
+        };
+        let deps: Vec<String> = content
+            .lines()
+            .map(|l| l.trim())
+            .filter(|l| !l.is_empty() && !l.starts_with('#'))
+            .filter_map(|l| l.split(&['=', '<', '>'][..]).next().map(|s| s.to_string()))
+            .take(10)
+            .collect();
+
+        Some(Detection {
+            project_types: vec!["python-pip".to_string()],
+            tech_stack: deps,
+            ..Detection::default()
+        })
+    }
+}
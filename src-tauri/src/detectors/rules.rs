@@ -0,0 +1,254 @@
+use super::{push_unique, read_error_message, read_text, Detection, ProjectDetector};
+use crate::settings::get_data_dir;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// 파일 내용 일치 조건
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ContentMatch {
+    /// 검사할 파일 (glob, 프로젝트 폴더 기준)
+    pub file: String,
+    /// 파일에 포함되어야 하는 문자열
+    pub contains: String,
+}
+
+/// 설명 추출 방법
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct DescriptionSource {
+    /// 설명을 읽을 파일 (glob, 프로젝트 폴더 기준)
+    pub file: String,
+    /// JSON 파일의 경우 값 위치 (예: "/description")
+    #[serde(default)]
+    pub json_pointer: Option<String>,
+    /// 텍스트 파일의 경우 이 접두어로 시작하는 줄의 나머지 (예: "// @description")
+    #[serde(default)]
+    pub line_prefix: Option<String>,
+}
+
+/// 사용자 정의 감지 규칙
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct DetectionRule {
+    pub name: String,
+    pub project_type: String,
+    /// 하나라도 존재하면 일치하는 파일 glob 목록
+    #[serde(default)]
+    pub files: Vec<String>,
+    /// 모두 만족해야 하는 내용 일치 조건
+    #[serde(default)]
+    pub content_matches: Vec<ContentMatch>,
+    #[serde(default)]
+    pub tech_stack: Vec<String>,
+    #[serde(default)]
+    pub description: Option<DescriptionSource>,
+    /// 태그가 없는 프로젝트에 적용할 기본 구분 태그
+    #[serde(default)]
+    pub categories: Vec<String>,
+    #[serde(default = "default_rule_priority")]
+    pub priority: i32,
+}
+
+/// 사용자 규칙은 내장 감지기보다 우선
+fn default_rule_priority() -> i32 {
+    110
+}
+
+pub(crate) fn get_detection_rules_file() -> PathBuf {
+    get_data_dir().join("detection-rules.json")
+}
+
+/// 사용자 정의 감지 규칙 로드 (파일이 없으면 빈 목록, 읽거나 파싱하지 못하면 오류)
+pub fn load_detection_rules() -> Result<Vec<DetectionRule>, String> {
+    let file_path = get_detection_rules_file();
+
+    if !file_path.exists() {
+        return Ok(Vec::new());
+    }
+
+    let content = read_text(&file_path).map_err(|e| {
+        format!(
+            "Failed to read detection rules file {}: {}",
+            file_path.display(),
+            read_error_message(&e).unwrap_or_else(|| e.to_string())
+        )
+    })?;
+    serde_json::from_str(&content).map_err(|e| {
+        format!(
+            "Invalid detection rules file {}: {}",
+            file_path.display(),
+            e
+        )
+    })
+}
+
+/// 사용자 정의 규칙으로 만든 감지기 목록
+pub fn load_rule_detectors() -> Result<Vec<Box<dyn ProjectDetector>>, String> {
+    Ok(load_detection_rules()?
+        .into_iter()
+        .map(|rule| Box::new(RuleDetector { rule }) as Box<dyn ProjectDetector>)
+        .collect())
+}
+
+/// 규칙 glob 탐색 시 최대 항목 수와 "**" 패턴의 최대 깊이
+const GLOB_MAX_ENTRIES: usize = 10_000;
+const GLOB_MAX_DEPTH: usize = 8;
+
+/// 프로젝트 폴더 기준 glob과 일치하는 파일 목록
+///
+/// 패턴의 폴더 깊이만큼만 내려가고 ("**"는 최대 깊이까지), 큰 프로젝트에서 오래 걸리지 않도록
+/// 탐색 항목 수를 제한 (.git과 폴더 심볼릭 링크는 따라가지 않음)
+fn matching_files(project_dir: &Path, pattern: &str) -> Vec<PathBuf> {
+    let pattern = pattern.trim_start_matches('/');
+    let Ok(matcher) = glob::Pattern::new(pattern) else {
+        return Vec::new();
+    };
+    let options = glob::MatchOptions {
+        require_literal_separator: true,
+        ..glob::MatchOptions::new()
+    };
+    let max_depth = if pattern.contains("**") {
+        GLOB_MAX_DEPTH
+    } else {
+        pattern.matches('/').count()
+    };
+
+    let mut files = Vec::new();
+    let mut visited = 0;
+    let mut stack = vec![(project_dir.to_path_buf(), 0)];
+    'walk: while let Some((dir, depth)) = stack.pop() {
+        let Ok(entries) = fs::read_dir(&dir) else {
+            continue;
+        };
+        for entry in entries.flatten() {
+            visited += 1;
+            if visited > GLOB_MAX_ENTRIES {
+                break 'walk;
+            }
+            let Ok(file_type) = entry.file_type() else {
+                continue;
+            };
+            let path = entry.path();
+            if file_type.is_dir() {
+                if depth < max_depth && entry.file_name() != ".git" {
+                    stack.push((path, depth + 1));
+                }
+            } else if path.is_file() {
+                let relative = path.strip_prefix(project_dir).unwrap_or(&path);
+                if matcher.matches_path_with(relative, options) {
+                    files.push(path);
+                }
+            }
+        }
+    }
+
+    files.sort();
+    files
+}
+
+/// 설명 추출 (읽지 못한 파일은 detection에 경고 기록)
+fn read_description(
+    project_dir: &Path,
+    source: &DescriptionSource,
+    detection: &mut Detection,
+) -> Option<String> {
+    for path in matching_files(project_dir, &source.file) {
+        let Some(content) = detection.read_optional(project_dir, &path) else {
+            continue;
+        };
+
+        let description = if let Some(pointer) = &source.json_pointer {
+            serde_json::from_str::<serde_json::Value>(&content)
+                .ok()
+                .and_then(|json| {
+                    json.pointer(pointer)
+                        .and_then(|v| v.as_str())
+                        .map(|s| s.to_string())
+                })
+        } else if let Some(prefix) = &source.line_prefix {
+            content
+                .lines()
+                .find_map(|line| line.trim().strip_prefix(prefix.as_str()))
+                .map(|rest| rest.trim().to_string())
+        } else {
+            content
+                .lines()
+                .map(|line| line.trim())
+                .find(|line| !line.is_empty())
+                .map(|line| line.to_string())
+        };
+
+        if let Some(description) = description.filter(|d| !d.is_empty()) {
+            return Some(description.chars().take(150).collect());
+        }
+    }
+
+    None
+}
+
+/// 사용자 정의 규칙 감지기
+pub struct RuleDetector {
+    rule: DetectionRule,
+}
+
+impl RuleDetector {
+    /// 규칙 일치 여부 (파일 glob 중 하나 이상 + 내용 조건 모두)
+    fn matches(&self, project_dir: &Path) -> bool {
+        let rule = &self.rule;
+        if rule.files.is_empty() && rule.content_matches.is_empty() {
+            return false;
+        }
+
+        let files_match = rule.files.is_empty()
+            || rule
+                .files
+                .iter()
+                .any(|pattern| !matching_files(project_dir, pattern).is_empty());
+
+        files_match
+            && rule.content_matches.iter().all(|condition| {
+                matching_files(project_dir, &condition.file)
+                    .iter()
+                    .filter_map(|path| read_text(path).ok())
+                    .any(|content| content.contains(&condition.contains))
+            })
+    }
+}
+
+impl ProjectDetector for RuleDetector {
+    fn name(&self) -> &str {
+        &self.rule.name
+    }
+
+    fn priority(&self) -> i32 {
+        self.rule.priority
+    }
+
+    fn markers(&self) -> Vec<&str> {
+        self.rule.files.iter().map(|f| f.as_str()).collect()
+    }
+
+    fn detect(&self, project_dir: &Path) -> Option<Detection> {
+        if !self.matches(project_dir) {
+            return None;
+        }
+
+        let mut detection = Detection {
+            project_types: vec![self.rule.project_type.clone()],
+            ..Detection::default()
+        };
+        if let Some(source) = &self.rule.description {
+            detection.description = read_description(project_dir, source, &mut detection);
+        }
+        for tech in &self.rule.tech_stack {
+            push_unique(&mut detection.tech_stack, tech.clone());
+        }
+        for category in &self.rule.categories {
+            push_unique(&mut detection.categories, category.clone());
+        }
+
+        Some(detection)
+    }
+}
@@ -0,0 +1,218 @@
+use super::{
+    declared_version, expand_package_globs, extend_unique, read_error_message, read_manifest,
+    read_text, relative_path, toml_error_message, toml_table_keys, Detection, ProjectDetector,
+};
+use crate::scanner::SubPackage;
+use std::fs;
+use std::path::{Path, PathBuf};
+use toml::Value;
+
+/// Cargo 워크스페이스 멤버 경로 확장 (glob 지원, exclude 반영)
+fn expand_cargo_members(project_path: &Path, workspace: &Value) -> Vec<PathBuf> {
+    let string_list = |key: &str| -> Vec<String> {
+        workspace
+            .get(key)
+            .and_then(|v| v.as_array())
+            .map(|arr| {
+                arr.iter()
+                    .filter_map(|v| v.as_str())
+                    .map(|s| s.to_string())
+                    .collect()
+            })
+            .unwrap_or_default()
+    };
+
+    let mut patterns = string_list("members");
+    patterns.extend(
+        string_list("exclude")
+            .into_iter()
+            .map(|e| format!("!{}", e)),
+    );
+    expand_package_globs(project_path, &patterns, "Cargo.toml")
+}
+
+/// 크레이트의 빌드 타겟 추출 ([lib], [[bin]] 및 기본 경로)
+fn cargo_targets(crate_path: &Path, manifest: &Value) -> Vec<String> {
+    let package_name = manifest
+        .get("package")
+        .and_then(|p| p.get("name"))
+        .and_then(|n| n.as_str())
+        .unwrap_or_default()
+        .to_string();
+    let mut targets = Vec::new();
+
+    if let Some(lib) = manifest.get("lib") {
+        let name = lib
+            .get("name")
+            .and_then(|n| n.as_str())
+            .unwrap_or(&package_name);
+        targets.push(format!("lib:{}", name));
+    } else if crate_path.join("src").join("lib.rs").exists() {
+        targets.push(format!("lib:{}", package_name));
+    }
+
+    if let Some(bins) = manifest.get("bin").and_then(|b| b.as_array()) {
+        for bin in bins {
+            if let Some(name) = bin.get("name").and_then(|n| n.as_str()) {
+                targets.push(format!("bin:{}", name));
+            }
+        }
+    }
+    if crate_path.join("src").join("main.rs").exists() {
+        let default_bin = format!("bin:{}", package_name);
+        if !targets.contains(&default_bin) {
+            targets.push(default_bin);
+        }
+    }
+    if let Ok(entries) = fs::read_dir(crate_path.join("src").join("bin")) {
+        for entry in entries.flatten() {
+            let path = entry.path();
+            if path.extension().and_then(|e| e.to_str()) == Some("rs") {
+                if let Some(stem) = path.file_stem() {
+                    let bin = format!("bin:{}", stem.to_string_lossy());
+                    if !targets.contains(&bin) {
+                        targets.push(bin);
+                    }
+                }
+            }
+        }
+    }
+
+    targets
+}
+
+/// Cargo.toml 감지기 (단일 크레이트 및 워크스페이스)
+pub struct CargoDetector;
+
+impl ProjectDetector for CargoDetector {
+    fn name(&self) -> &str {
+        "cargo"
+    }
+
+    fn priority(&self) -> i32 {
+        80
+    }
+
+    fn markers(&self) -> Vec<&str> {
+        vec!["Cargo.toml"]
+    }
+
+    fn detect(&self, project_dir: &Path) -> Option<Detection> {
+        let content = match read_manifest(project_dir, "Cargo.toml") {
+            Ok(Some(content)) => content,
+            Ok(None) => return None,
+            Err(message) => return Some(Detection::failed("Cargo.toml", message)),
+        };
+        let manifest = match content.parse::<Value>() {
+            Ok(manifest) => manifest,
+            Err(e) => {
+                return Some(Detection::failed(
+                    "Cargo.toml",
+                    toml_error_message(&content, &e),
+                ))
+            }
+        };
+
+        let workspace = manifest.get("workspace");
+        let workspace_package = workspace.and_then(|w| w.get("package"));
+
+        // 설명: [package] 우선, 워크스페이스 상속 시 [workspace.package] 사용
+        let description = manifest
+            .get("package")
+            .and_then(|p| p.get("description"))
+            .and_then(|d| d.as_str())
+            .or_else(|| {
+                workspace_package
+                    .and_then(|p| p.get("description"))
+                    .and_then(|d| d.as_str())
+            })
+            .map(|d| d.to_string());
+
+        // 버전: [package].version, version.workspace = true면 [workspace.package].version
+        let workspace_version = workspace_package
+            .and_then(|p| p.get("version"))
+            .and_then(|v| v.as_str());
+        let version = match manifest.get("package").and_then(|p| p.get("version")) {
+            Some(Value::String(version)) => Some(version.as_str()),
+            Some(_) => workspace_version,
+            None if manifest.get("package").is_none() => workspace_version,
+            None => None,
+        };
+
+        let mut detection = Detection {
+            project_types: vec!["rust".to_string()],
+            description,
+            versions: declared_version("Cargo.toml", version)
+                .into_iter()
+                .collect(),
+            ..Detection::default()
+        };
+
+        let mut deps = toml_table_keys(manifest.get("dependencies"));
+        deps.extend(toml_table_keys(manifest.get("dev-dependencies")));
+        if manifest.get("package").is_some() {
+            detection
+                .targets
+                .extend(cargo_targets(project_dir, &manifest));
+        }
+
+        // 워크스페이스 멤버 크레이트
+        if let Some(workspace) = workspace {
+            deps.extend(toml_table_keys(workspace.get("dependencies")));
+
+            let mut member_names = Vec::new();
+            for member_path in expand_cargo_members(project_dir, workspace) {
+                let manifest_path = relative_path(project_dir, &member_path.join("Cargo.toml"));
+                let member = match read_text(&member_path.join("Cargo.toml")) {
+                    Ok(content) => match content.parse::<Value>() {
+                        Ok(member) => member,
+                        Err(e) => {
+                            detection.warn(manifest_path, toml_error_message(&content, &e));
+                            continue;
+                        }
+                    },
+                    Err(e) => {
+                        let message = read_error_message(&e).unwrap_or_else(|| e.to_string());
+                        detection.warn(manifest_path, message);
+                        continue;
+                    }
+                };
+
+                let name = member
+                    .get("package")
+                    .and_then(|p| p.get("name"))
+                    .and_then(|n| n.as_str())
+                    .map(|s| s.to_string())
+                    .unwrap_or_else(|| {
+                        member_path
+                            .file_name()
+                            .unwrap_or_default()
+                            .to_string_lossy()
+                            .to_string()
+                    });
+
+                let mut member_deps = toml_table_keys(member.get("dependencies"));
+                member_deps.extend(toml_table_keys(member.get("dev-dependencies")));
+                deps.extend(member_deps.clone());
+                detection
+                    .targets
+                    .extend(cargo_targets(&member_path, &member));
+
+                member_names.push(name.clone());
+                detection.packages.push(SubPackage {
+                    name,
+                    path: relative_path(project_dir, &member_path),
+                    package_type: "rust".to_string(),
+                    dependencies: member_deps,
+                    internal_dependencies: Vec::new(),
+                });
+            }
+
+            // 내부 멤버 크레이트는 기술 스택에서 제외
+            deps.retain(|d| !member_names.contains(d));
+        }
+
+        extend_unique(&mut detection.tech_stack, deps, 10);
+        Some(detection)
+    }
+}
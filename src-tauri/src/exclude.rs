@@ -0,0 +1,233 @@
+use crate::detectors::{read_error_message, read_text};
+use std::path::{Component, Path};
+
+/// 스캔 루트 최상위에 두는 제외 패턴 파일
+pub const IGNORE_FILE_NAME: &str = ".code-tare-ignore";
+
+/// gitignore 형식 패턴 한 줄
+#[derive(Debug, Clone)]
+struct ExcludeRule {
+    pattern: glob::Pattern,
+    /// "!"로 시작하는 재포함 패턴
+    negated: bool,
+    /// "/"로 끝나는 폴더 전용 패턴
+    dir_only: bool,
+    /// 중간에 "/"가 있어 기준 폴더부터 전체 경로로 비교하는 패턴
+    anchored: bool,
+    /// 패턴이 적용되는 기준 폴더 (루트 기준 상대 경로, 최상위는 "")
+    base: String,
+}
+
+/// glob 및 gitignore 형식 제외 패턴 매처
+///
+/// 나중에 추가된 패턴이 우선하며 ("!" 재포함 포함), 제외된 폴더의 하위 경로는 모두 제외됨.
+/// 경로는 항상 스캔 루트 기준 상대 경로로 전달
+#[derive(Debug, Clone, Default)]
+pub struct ExcludeMatcher {
+    rules: Vec<ExcludeRule>,
+}
+
+impl ExcludeMatcher {
+    /// 빈 매처 (아무것도 제외하지 않음)
+    pub fn new() -> Self {
+        ExcludeMatcher::default()
+    }
+
+    /// 설정의 제외 목록과 루트의 .code-tare-ignore 파일로 매처 생성
+    pub fn for_root(root: &Path, patterns: &[String]) -> Self {
+        let mut matcher = ExcludeMatcher::new();
+        for pattern in patterns {
+            matcher.add_pattern(pattern);
+        }
+        matcher.add_ignore_file(&root.join(IGNORE_FILE_NAME), "");
+        matcher
+    }
+
+    /// 패턴 한 줄 추가 (빈 줄, 주석, 잘못된 패턴은 무시)
+    pub fn add_pattern(&mut self, line: &str) {
+        self.add_pattern_at(line, "");
+    }
+
+    /// 특정 하위 폴더 기준 패턴 한 줄 추가 (하위 폴더의 .gitignore 등)
+    pub fn add_pattern_at(&mut self, line: &str, base: &str) {
+        if let Some(rule) = parse_rule(line, base) {
+            self.rules.push(rule);
+        }
+    }
+
+    /// gitignore 형식 파일의 패턴 추가, 파일이 없거나 읽을 수 없으면 (크기 제한 초과 포함) false
+    pub fn add_ignore_file(&mut self, path: &Path, base: &str) -> bool {
+        let content = match read_text(path) {
+            Ok(content) => content,
+            Err(e) => {
+                if let Some(message) = read_error_message(&e) {
+                    println!("Skipped ignore file {}: {}", path.display(), message);
+                }
+                return false;
+            }
+        };
+        for line in content.lines() {
+            self.add_pattern_at(line, base);
+        }
+        true
+    }
+
+    /// 루트 기준 상대 경로가 제외 대상인지 확인 (상위 폴더가 제외되면 하위도 제외)
+    pub fn is_excluded(&self, relative_path: &Path, is_dir: bool) -> bool {
+        if self.rules.is_empty() {
+            return false;
+        }
+
+        let components: Vec<String> = relative_path
+            .components()
+            .filter_map(|c| match c {
+                Component::Normal(name) => Some(name.to_string_lossy().to_string()),
+                _ => None,
+            })
+            .collect();
+
+        let mut current = String::new();
+        for (index, component) in components.iter().enumerate() {
+            if !current.is_empty() {
+                current.push('/');
+            }
+            current.push_str(component);
+
+            let component_is_dir = index + 1 < components.len() || is_dir;
+            if self.matches(&current, component, component_is_dir) {
+                return true;
+            }
+        }
+
+        false
+    }
+
+    /// 경로 하나에 대해 마지막으로 일치한 패턴 기준 제외 여부
+    fn matches(&self, path: &str, file_name: &str, is_dir: bool) -> bool {
+        let options = glob::MatchOptions {
+            case_sensitive: true,
+            require_literal_separator: true,
+            require_literal_leading_dot: false,
+        };
+
+        let mut excluded = false;
+        for rule in &self.rules {
+            if rule.dir_only && !is_dir {
+                continue;
+            }
+
+            let Some(relative) = strip_base(path, &rule.base) else {
+                continue;
+            };
+            let target = if rule.anchored { relative } else { file_name };
+
+            if rule.pattern.matches_with(target, options) {
+                excluded = !rule.negated;
+            }
+        }
+        excluded
+    }
+}
+
+/// 기준 폴더 하위 경로이면 기준 폴더 이후 부분
+fn strip_base<'a>(path: &'a str, base: &str) -> Option<&'a str> {
+    if base.is_empty() {
+        return Some(path);
+    }
+    path.strip_prefix(base)
+        .and_then(|rest| rest.strip_prefix('/'))
+        .filter(|rest| !rest.is_empty())
+}
+
+/// gitignore 형식 한 줄 파싱
+fn parse_rule(line: &str, base: &str) -> Option<ExcludeRule> {
+    let mut pattern = line.trim();
+    if pattern.is_empty() || pattern.starts_with('#') {
+        return None;
+    }
+
+    let negated = pattern.starts_with('!');
+    if negated {
+        pattern = &pattern[1..];
+    }
+    // "\#", "\!"로 시작하는 이름은 문자 그대로
+    if pattern.starts_with("\\#") || pattern.starts_with("\\!") {
+        pattern = &pattern[1..];
+    }
+
+    let dir_only = pattern.ends_with('/');
+    let pattern = pattern.trim_end_matches('/');
+
+    // 앞이나 중간에 "/"가 있으면 기준 폴더에 고정, 없으면 어느 깊이의 이름과도 비교
+    let anchored = pattern.contains('/');
+    let pattern = pattern.trim_start_matches('/');
+    if pattern.is_empty() {
+        return None;
+    }
+
+    Some(ExcludeRule {
+        pattern: glob::Pattern::new(pattern).ok()?,
+        negated,
+        dir_only,
+        anchored,
+        base: base.trim_matches('/').to_string(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn matcher(patterns: &[&str]) -> ExcludeMatcher {
+        let mut matcher = ExcludeMatcher::new();
+        for pattern in patterns {
+            matcher.add_pattern(pattern);
+        }
+        matcher
+    }
+
+    #[test]
+    fn unanchored_patterns_match_names_at_any_depth() {
+        let matcher = matcher(&["node_modules/", "*.log"]);
+        assert!(matcher.is_excluded(Path::new("node_modules"), true));
+        assert!(matcher.is_excluded(Path::new("web/node_modules/react/index.js"), false));
+        assert!(matcher.is_excluded(Path::new("logs/debug.log"), false));
+        // 폴더 전용 패턴은 같은 이름의 파일과 일치하지 않음
+        assert!(!matcher.is_excluded(Path::new("node_modules"), false));
+    }
+
+    #[test]
+    fn anchored_patterns_match_from_the_base() {
+        let matcher = matcher(&["/build", "docs/*.md"]);
+        assert!(matcher.is_excluded(Path::new("build"), true));
+        assert!(!matcher.is_excluded(Path::new("src/build"), true));
+        assert!(matcher.is_excluded(Path::new("docs/guide.md"), false));
+        assert!(!matcher.is_excluded(Path::new("docs/api/guide.md"), false));
+    }
+
+    #[test]
+    fn later_negations_reinclude_except_under_excluded_folders() {
+        let matcher = matcher(&["*.log", "!keep.log", "build/", "!build/keep.txt"]);
+        assert!(matcher.is_excluded(Path::new("other.log"), false));
+        assert!(!matcher.is_excluded(Path::new("keep.log"), false));
+        assert!(matcher.is_excluded(Path::new("build/keep.txt"), false));
+    }
+
+    #[test]
+    fn patterns_at_a_base_only_apply_below_it() {
+        let mut matcher = ExcludeMatcher::new();
+        matcher.add_pattern_at("*.tmp", "sub");
+        assert!(matcher.is_excluded(Path::new("sub/a.tmp"), false));
+        assert!(matcher.is_excluded(Path::new("sub/deep/a.tmp"), false));
+        assert!(!matcher.is_excluded(Path::new("a.tmp"), false));
+        assert!(!matcher.is_excluded(Path::new("subway/a.tmp"), false));
+    }
+
+    #[test]
+    fn comments_and_escapes() {
+        let matcher = matcher(&["# comment", "", r"\#notes", r"\!important"]);
+        assert!(!matcher.is_excluded(Path::new("# comment"), false));
+        assert!(matcher.is_excluded(Path::new("#notes"), false));
+        assert!(matcher.is_excluded(Path::new("!important"), false));
+    }
+}
@@ -1,7 +1,15 @@
+mod cache;
 mod commands;
+mod detectors;
+mod exclude;
+mod git;
+mod recent;
+mod readme;
+mod release;
 mod scanner;
 mod settings;
 mod tags;
+mod watcher;
 
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
@@ -16,10 +24,12 @@ pub fn run() {
             .build(),
         )?;
       }
+      watcher::start_project_watcher(app.handle().clone());
       Ok(())
     })
     .invoke_handler(tauri::generate_handler![
       commands::get_projects,
+      commands::start_scan,
       commands::get_available_tags,
       commands::get_tags,
       commands::save_tags,
@@ -27,7 +37,11 @@ pub fn run() {
       commands::open_project,
       commands::get_settings,
       commands::update_settings,
+      commands::get_commit_activity,
       commands::get_readme,
+      commands::get_project_readme,
+      commands::open_url,
+      commands::reveal_project_file,
     ])
     .run(tauri::generate_context!())
     .expect("error while running tauri application");
@@ -0,0 +1,405 @@
+use crate::detectors::{read_error_message, read_text};
+use ammonia::{UrlRelative, UrlRelativeEvaluate};
+use pulldown_cmark::{html, Options, Parser};
+use serde::Serialize;
+use std::borrow::Cow;
+use std::collections::HashSet;
+use std::env;
+use std::fs;
+use std::path::{Component, Path, PathBuf};
+
+/// 언어 구분 없는 README 파일 (우선순위 순, 대소문자 무시)
+const README_FILES: &[&str] = &[
+    "readme.md",
+    "readme.markdown",
+    "readme.rst",
+    "readme.txt",
+    "readme",
+];
+
+/// 언어별 README 확장자 (README.ko.md 등)
+const LOCALIZED_EXTENSIONS: &[&str] = &["md", "markdown", "rst", "txt"];
+
+/// 선호 언어를 설정하지 않았을 때 확인할 환경 변수
+const LOCALE_ENV_VARS: &[&str] = &["LC_ALL", "LC_MESSAGES", "LANG"];
+
+/// 로컬 파일을 웹뷰에서 여는 Tauri asset 프로토콜 주소 (프론트엔드 convertFileSrc와 같은 형식)
+#[cfg(windows)]
+const ASSET_URL_PREFIX: &str = "http://asset.localhost/";
+#[cfg(not(windows))]
+const ASSET_URL_PREFIX: &str = "asset://localhost/";
+
+/// README 형식
+#[derive(Debug, Serialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum ReadmeFormat {
+    Markdown,
+    Rst,
+    Text,
+}
+
+/// 프로젝트 README 원문
+#[derive(Debug, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectReadme {
+    /// 실제로 읽은 파일 경로 (심볼릭 링크를 따라간 경로)
+    pub path: String,
+    /// 프로젝트 폴더 안의 파일 이름
+    pub file: String,
+    /// 언어별 README를 찾은 경우 파일 이름의 언어 코드 (ko, pt-br 등)
+    pub locale: Option<String>,
+    pub format: ReadmeFormat,
+    pub content: String,
+    /// Markdown README를 렌더링한 HTML (스크립트 제거, 상대 경로는 asset URL로 변환)
+    pub html: Option<String>,
+}
+
+/// 설정의 선호 언어, 없으면 시스템 언어 (LC_ALL, LC_MESSAGES, LANG 순)
+pub fn preferred_locale(setting: Option<&str>) -> Option<String> {
+    setting
+        .map(str::to_string)
+        .filter(|locale| !locale.trim().is_empty())
+        .or_else(|| {
+            LOCALE_ENV_VARS
+                .iter()
+                .find_map(|var| env::var(var).ok().filter(|value| !value.is_empty()))
+        })
+}
+
+/// 언어 코드에서 README 파일 이름에 쓸 후보 ("ko_KR.UTF-8" → ko-kr, ko_kr, ko)
+fn locale_candidates(locale: &str) -> Vec<String> {
+    let normalized = locale
+        .trim()
+        .split(['.', '@'])
+        .next()
+        .unwrap_or_default()
+        .replace('_', "-")
+        .to_lowercase();
+    if normalized.is_empty() || normalized == "c" || normalized == "posix" {
+        return Vec::new();
+    }
+
+    let mut candidates = vec![normalized.clone()];
+    if normalized.contains('-') {
+        candidates.push(normalized.replace('-', "_"));
+    }
+    if let Some((language, _)) = normalized.split_once('-') {
+        candidates.push(language.to_string());
+    }
+    candidates
+}
+
+fn readme_format(file_name: &str) -> ReadmeFormat {
+    let lower = file_name.to_lowercase();
+    if lower.ends_with(".md") || lower.ends_with(".markdown") {
+        ReadmeFormat::Markdown
+    } else if lower.ends_with(".rst") {
+        ReadmeFormat::Rst
+    } else {
+        ReadmeFormat::Text
+    }
+}
+
+/// 프로젝트 폴더의 README 파일과 언어 코드 (언어별 README 우선, 없으면 기본 README)
+pub fn resolve_readme(
+    project_dir: &Path,
+    locale: Option<&str>,
+) -> Option<(PathBuf, Option<String>)> {
+    let mut files: Vec<String> = fs::read_dir(project_dir)
+        .ok()?
+        .flatten()
+        .filter(|entry| entry.path().is_file())
+        .map(|entry| entry.file_name().to_string_lossy().to_string())
+        .collect();
+    // 대소문자만 다른 파일이 여럿이면 README.md처럼 대문자 이름 우선
+    files.sort();
+    let find = |candidate: &str| files.iter().find(|file| file.to_lowercase() == candidate);
+
+    for tag in locale.map(locale_candidates).unwrap_or_default() {
+        for extension in LOCALIZED_EXTENSIONS {
+            if let Some(file) = find(&format!("readme.{}.{}", tag, extension)) {
+                return Some((project_dir.join(file), Some(tag)));
+            }
+        }
+    }
+
+    README_FILES
+        .iter()
+        .find_map(|candidate| find(candidate))
+        .map(|file| (project_dir.join(file), None))
+}
+
+/// 프로젝트 README 읽기 (프로젝트 폴더 밖을 가리키는 링크는 읽지 않음)
+pub fn read_project_readme(
+    project_path: &str,
+    locale: Option<&str>,
+) -> Result<ProjectReadme, String> {
+    let project_dir = Path::new(project_path)
+        .canonicalize()
+        .map_err(|e| format!("Failed to open project folder: {}", e))?;
+    if !project_dir.is_dir() {
+        return Err(format!("Not a project folder: {}", project_path));
+    }
+
+    let (path, locale) =
+        resolve_readme(&project_dir, locale).ok_or_else(|| "README not found".to_string())?;
+    let file = path
+        .file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_default();
+
+    let real_path = path
+        .canonicalize()
+        .map_err(|e| format!("Failed to open {}: {}", file, e))?;
+    if !real_path.starts_with(&project_dir) {
+        return Err(format!(
+            "{} points outside the project folder: {}",
+            file,
+            real_path.display()
+        ));
+    }
+
+    let content = read_text(&real_path).map_err(|e| {
+        let message = read_error_message(&e).unwrap_or_else(|| e.to_string());
+        format!("Failed to read {}: {}", file, message)
+    })?;
+
+    let format = readme_format(&file);
+    let html = (format == ReadmeFormat::Markdown).then(|| {
+        let base_dir = real_path.parent().unwrap_or(&project_dir);
+        render_markdown(&content, &project_dir, base_dir)
+    });
+
+    Ok(ProjectReadme {
+        path: real_path.to_string_lossy().to_string(),
+        file,
+        locale,
+        format,
+        content,
+        html,
+    })
+}
+
+/// JavaScript encodeURIComponent와 같은 규칙으로 인코딩
+fn encode_uri_component(value: &str) -> String {
+    let mut encoded = String::with_capacity(value.len());
+    for byte in value.bytes() {
+        if byte.is_ascii_alphanumeric() || b"-_.!~*'()".contains(&byte) {
+            encoded.push(byte as char);
+        } else {
+            encoded.push_str(&format!("%{:02X}", byte));
+        }
+    }
+    encoded
+}
+
+/// URL 경로의 %XX 디코딩 (잘못된 형식은 그대로 둠)
+fn decode_percent(value: &str) -> String {
+    let bytes = value.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        let hex = bytes
+            .get(i + 1..i + 3)
+            .and_then(|h| std::str::from_utf8(h).ok())
+            .and_then(|h| u8::from_str_radix(h, 16).ok());
+        match (bytes[i], hex) {
+            (b'%', Some(byte)) => {
+                decoded.push(byte);
+                i += 3;
+            }
+            (byte, _) => {
+                decoded.push(byte);
+                i += 1;
+            }
+        }
+    }
+    String::from_utf8_lossy(&decoded).to_string()
+}
+
+/// README 안의 상대 경로를 asset URL로 변환 (프로젝트 폴더 밖을 가리키면 None)
+///
+/// "/"로 시작하는 경로는 저장소 웹 화면처럼 프로젝트 폴더 기준, 나머지는 README 폴더 기준
+fn project_asset_url(project_dir: &Path, base_dir: &Path, url: &str) -> Option<String> {
+    // 문서 안 제목 링크는 그대로
+    if url.starts_with('#') {
+        return Some(url.to_string());
+    }
+    let path = url.split(['?', '#']).next().unwrap_or_default();
+    if path.is_empty() {
+        return None;
+    }
+
+    let decoded = decode_percent(path);
+    let (base, relative) = match decoded.strip_prefix('/') {
+        Some(relative) => (project_dir, relative),
+        None => (base_dir, decoded.as_str()),
+    };
+
+    let mut target = base.to_path_buf();
+    for component in Path::new(relative).components() {
+        match component {
+            Component::Normal(part) => target.push(part),
+            Component::ParentDir => {
+                target.pop();
+            }
+            _ => {}
+        }
+    }
+    if !target.starts_with(project_dir) {
+        return None;
+    }
+    // 프로젝트 안의 심볼릭 링크가 밖을 가리키는 경우
+    if let Ok(real_target) = target.canonicalize() {
+        if !real_target.starts_with(project_dir) {
+            return None;
+        }
+    }
+
+    Some(format!(
+        "{}{}",
+        ASSET_URL_PREFIX,
+        encode_uri_component(&target.to_string_lossy())
+    ))
+}
+
+/// 렌더링한 HTML의 상대 경로를 asset URL로 바꾸는 ammonia 평가기
+struct ProjectAssetUrls {
+    project_dir: PathBuf,
+    base_dir: PathBuf,
+}
+
+impl UrlRelativeEvaluate<'_> for ProjectAssetUrls {
+    fn evaluate<'url>(&self, url: &'url str) -> Option<Cow<'url, str>> {
+        project_asset_url(&self.project_dir, &self.base_dir, url).map(Cow::Owned)
+    }
+}
+
+/// Markdown을 안전한 HTML로 변환
+///
+/// GFM 표, 체크리스트, 취소선, 각주와 언어 표시가 있는 코드 블록을 지원하며
+/// 스크립트, 이벤트 속성, 허용하지 않은 URL 스킴은 제거.
+/// project_dir은 canonicalize된 프로젝트 폴더, base_dir은 README가 있는 폴더
+pub fn render_markdown(content: &str, project_dir: &Path, base_dir: &Path) -> String {
+    let options = Options::ENABLE_TABLES
+        | Options::ENABLE_TASKLISTS
+        | Options::ENABLE_STRIKETHROUGH
+        | Options::ENABLE_FOOTNOTES;
+    let mut unsafe_html = String::new();
+    html::push_html(&mut unsafe_html, Parser::new_ext(content, options));
+
+    let asset_urls = ProjectAssetUrls {
+        project_dir: project_dir.to_path_buf(),
+        base_dir: base_dir.to_path_buf(),
+    };
+
+    ammonia::Builder::default()
+        // 체크리스트 (읽기 전용 체크박스만)
+        .add_tags(["input"])
+        .add_tag_attributes("input", ["type", "checked", "disabled"])
+        .set_tag_attribute_value("input", "disabled", "")
+        .attribute_filter(|element, attribute, value| {
+            if element == "input" && attribute == "type" && value != "checkbox" {
+                return None;
+            }
+            Some(value.into())
+        })
+        // 코드 블록 언어 (class="language-rust"), 표 정렬
+        .add_tag_attributes("code", ["class"])
+        .add_tag_attributes("th", ["style"])
+        .add_tag_attributes("td", ["style"])
+        .filter_style_properties(HashSet::from(["text-align"]))
+        .url_relative(UrlRelative::Custom(Box::new(asset_urls)))
+        .clean(&unsafe_html)
+        .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 테스트마다 비어 있는 임시 폴더
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("code-tare-readme-{}-{}", std::process::id(), name));
+        fs::remove_dir_all(&dir).ok();
+        fs::create_dir_all(&dir).unwrap();
+        dir.canonicalize().unwrap()
+    }
+
+    #[test]
+    fn locale_candidates_normalize_system_locales() {
+        assert_eq!(
+            locale_candidates("ko_KR.UTF-8"),
+            vec!["ko-kr", "ko_kr", "ko"]
+        );
+        assert_eq!(locale_candidates("pt-BR"), vec!["pt-br", "pt_br", "pt"]);
+        assert_eq!(locale_candidates("en"), vec!["en"]);
+        assert!(locale_candidates("C").is_empty());
+        assert!(locale_candidates("POSIX").is_empty());
+    }
+
+    #[test]
+    fn resolve_readme_prefers_the_localized_file() {
+        let dir = temp_dir("locale");
+        fs::write(dir.join("README.md"), "# App").unwrap();
+        fs::write(dir.join("readme.ko.md"), "# 앱").unwrap();
+
+        let (path, locale) = resolve_readme(&dir, Some("ko_KR.UTF-8")).unwrap();
+        assert_eq!(path, dir.join("readme.ko.md"));
+        assert_eq!(locale.as_deref(), Some("ko"));
+
+        let (path, locale) = resolve_readme(&dir, Some("fr")).unwrap();
+        assert_eq!(path, dir.join("README.md"));
+        assert_eq!(locale, None);
+
+        fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn project_asset_url_resolves_paths_inside_the_project() {
+        let project = Path::new("/projects/app");
+        let docs = Path::new("/projects/app/docs");
+        let asset = |path: &str| format!("{}{}", ASSET_URL_PREFIX, encode_uri_component(path));
+
+        assert_eq!(
+            project_asset_url(project, docs, "../img/logo.png?raw=1"),
+            Some(asset("/projects/app/img/logo.png"))
+        );
+        assert_eq!(
+            project_asset_url(project, docs, "/docs/my%20guide.md#intro"),
+            Some(asset("/projects/app/docs/my guide.md"))
+        );
+        assert_eq!(
+            project_asset_url(project, docs, "#usage"),
+            Some("#usage".to_string())
+        );
+    }
+
+    #[test]
+    fn project_asset_url_rejects_traversal_outside_the_project() {
+        let project = Path::new("/projects/app");
+        let docs = Path::new("/projects/app/docs");
+
+        assert_eq!(project_asset_url(project, docs, "../../secret.txt"), None);
+        assert_eq!(project_asset_url(project, docs, "/../other/file"), None);
+        assert_eq!(
+            project_asset_url(project, docs, "%2e%2e/%2e%2e/etc/passwd"),
+            None
+        );
+        assert_eq!(project_asset_url(project, docs, "?query"), None);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn project_asset_url_rejects_symlinks_leaving_the_project() {
+        let root = temp_dir("symlink");
+        let project = root.join("app");
+        fs::create_dir_all(&project).unwrap();
+        fs::write(root.join("secret.txt"), "secret").unwrap();
+        std::os::unix::fs::symlink(root.join("secret.txt"), project.join("link.txt")).unwrap();
+
+        assert_eq!(project_asset_url(&project, &project, "link.txt"), None);
+
+        fs::remove_dir_all(&root).ok();
+    }
+}
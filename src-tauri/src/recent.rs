@@ -0,0 +1,29 @@
+use crate::settings::get_data_dir;
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+
+fn get_last_opened_file() -> PathBuf {
+    get_data_dir().join("last-opened.json")
+}
+
+/// 프로젝트 경로별 Code-tare에서 마지막으로 연 시각 (RFC 3339)
+pub fn load_last_opened() -> HashMap<String, String> {
+    match fs::read_to_string(get_last_opened_file()) {
+        Ok(content) => serde_json::from_str(&content).unwrap_or_default(),
+        Err(_) => HashMap::new(),
+    }
+}
+
+/// 프로젝트를 연 시각 기록
+pub fn record_project_opened(project_path: &str) -> Result<(), String> {
+    let mut last_opened = load_last_opened();
+    last_opened.insert(project_path.to_string(), chrono::Utc::now().to_rfc3339());
+
+    let content = serde_json::to_string_pretty(&last_opened)
+        .map_err(|e| format!("Failed to serialize last opened times: {}", e))?;
+    fs::write(get_last_opened_file(), content)
+        .map_err(|e| format!("Failed to write last opened file: {}", e))?;
+
+    Ok(())
+}
@@ -0,0 +1,327 @@
+use crate::detectors::{first_existing, Detection};
+use crate::git::read_latest_tag;
+use crate::scanner::DeclaredVersion;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+const CHANGELOG_FILES: &[&str] = &["CHANGELOG.md", "Changelog.md", "changelog.md", "CHANGES.md"];
+
+/// CHANGELOG 본문 최대 길이 (문자 수)
+const CHANGELOG_NOTES_LIMIT: usize = 2000;
+
+/// CHANGELOG의 항목 하나
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ChangelogEntry {
+    /// 제목 원문 (예: "[1.2.0] - 2024-05-01")
+    pub heading: String,
+    pub version: Option<String>,
+    /// 제목에 포함된 YYYY-MM-DD 날짜
+    pub date: Option<String>,
+    pub notes: String,
+}
+
+/// CHANGELOG 파일 요약
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Changelog {
+    /// 프로젝트 폴더 기준 파일 이름
+    pub file: String,
+    /// 가장 최근 릴리스 항목
+    pub latest: Option<ChangelogEntry>,
+    /// "Unreleased" 항목 (Keep a Changelog 형식)
+    pub unreleased: Option<ChangelogEntry>,
+}
+
+/// 버전 및 릴리스 정보
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct ReleaseInfo {
+    /// 매니페스트에 선언된 버전
+    pub declared_versions: Vec<DeclaredVersion>,
+    pub latest_tag: Option<String>,
+    /// 최근 태그 이후 커밋 수
+    pub commits_since_tag: Option<usize>,
+    pub changelog: Option<Changelog>,
+    /// 태그 이후 커밋이 있거나 CHANGELOG의 Unreleased 항목에 내용이 있으면 true
+    pub has_unreleased_changes: bool,
+}
+
+/// 제목에서 버전 추출 ("[v1.2.0]", "1.2.0 (2024-05-01)", "Version 2.0" 등)
+fn heading_version(heading: &str) -> Option<String> {
+    heading
+        .split(|c: char| c.is_whitespace() || matches!(c, '[' | ']' | '(' | ')' | ','))
+        .map(|token| token.trim_start_matches(['v', 'V']))
+        .find(|token| {
+            token.starts_with(|c: char| c.is_ascii_digit())
+                && token.contains('.')
+                && !is_iso_date(token)
+        })
+        .map(|token| token.to_string())
+}
+
+fn is_iso_date(token: &str) -> bool {
+    let bytes = token.as_bytes();
+    bytes.len() == 10
+        && bytes[4] == b'-'
+        && bytes[7] == b'-'
+        && token
+            .chars()
+            .enumerate()
+            .all(|(i, c)| i == 4 || i == 7 || c.is_ascii_digit())
+}
+
+/// 제목에서 YYYY-MM-DD 날짜 추출
+fn heading_date(heading: &str) -> Option<String> {
+    heading
+        .split(|c: char| !c.is_ascii_digit() && c != '-')
+        .find(|token| is_iso_date(token))
+        .map(|token| token.to_string())
+}
+
+/// Markdown 제목 수준과 텍스트 ("## 1.0.0" → (2, "1.0.0"))
+fn markdown_heading(line: &str) -> Option<(usize, &str)> {
+    let level = line.chars().take_while(|c| *c == '#').count();
+    if level == 0 || level > 6 {
+        return None;
+    }
+    let text = line[level..].strip_prefix(' ')?;
+    Some((level, text.trim().trim_end_matches('#').trim()))
+}
+
+/// setext 제목 밑줄의 제목 수준 ("===" → 1, "---" → 2)
+fn setext_underline_level(line: &str) -> Option<usize> {
+    let trimmed = line.trim();
+    if trimmed.is_empty() || line.starts_with("    ") {
+        return None;
+    }
+    if trimmed.chars().all(|c| c == '=') {
+        Some(1)
+    } else if trimmed.chars().all(|c| c == '-') {
+        Some(2)
+    } else {
+        None
+    }
+}
+
+/// setext 제목이 될 수 있는 텍스트 줄 (빈 줄, 목록, 인용, 코드 블록 제외)
+fn is_setext_text(line: &str) -> bool {
+    let trimmed = line.trim();
+    !trimmed.is_empty()
+        && !line.starts_with("    ")
+        && markdown_heading(line).is_none()
+        && !["- ", "* ", "+ ", ">", "```", "~~~"]
+            .iter()
+            .any(|prefix| trimmed.starts_with(prefix))
+}
+
+/// 코드 블록 울타리 줄 ("```rust", "~~~")의 문자와 길이
+fn code_fence(line: &str) -> Option<(char, usize)> {
+    let trimmed = line.trim_start();
+    if line.len() - trimmed.len() > 3 {
+        return None;
+    }
+    let marker = trimmed.chars().next().filter(|c| *c == '`' || *c == '~')?;
+    let length = trimmed.chars().take_while(|c| *c == marker).count();
+    (length >= 3).then_some((marker, length))
+}
+
+/// CHANGELOG 제목 목록 (제목 줄 번호, 수준, 텍스트, 본문 시작 줄 번호)
+///
+/// ATX 제목 ("## 1.0.0")과 setext 제목 (다음 줄이 "-----" 또는 "=====") 모두 인식하며,
+/// 코드 블록 안의 줄은 제목으로 보지 않음
+fn changelog_headings<'a>(lines: &[&'a str]) -> Vec<(usize, usize, &'a str, usize)> {
+    let mut headings = Vec::new();
+    let mut open_fence: Option<(char, usize)> = None;
+    let mut index = 0;
+    while index < lines.len() {
+        let line = lines[index];
+        if let Some((marker, length)) = code_fence(line) {
+            match open_fence {
+                None => open_fence = Some((marker, length)),
+                // 같은 문자로 여는 울타리 이상 길이이고 뒤에 다른 글자가 없어야 닫힘
+                Some((open_marker, open_length))
+                    if marker == open_marker
+                        && length >= open_length
+                        && line.trim().chars().all(|c| c == marker) =>
+                {
+                    open_fence = None
+                }
+                Some(_) => {}
+            }
+        } else if open_fence.is_some() {
+            // 코드 블록 안
+        } else if let Some((level, text)) = markdown_heading(line) {
+            headings.push((index, level, text, index + 1));
+        } else if let Some(level) = lines
+            .get(index + 1)
+            .filter(|_| is_setext_text(line))
+            .and_then(|next| setext_underline_level(next))
+        {
+            headings.push((index, level, line.trim(), index + 2));
+            index += 1;
+        }
+        index += 1;
+    }
+    headings
+}
+
+/// CHANGELOG 내용에서 Unreleased 항목과 최근 릴리스 항목 추출
+///
+/// 버전 항목은 2수준 제목 ("## 1.0.0" 또는 "-----" 밑줄)을 기준으로 하며,
+/// 2수준 제목이 없으면 문서 제목을 제외한 1수준 제목 사용
+fn parse_changelog(content: &str) -> (Option<ChangelogEntry>, Option<ChangelogEntry>) {
+    let lines: Vec<&str> = content.lines().collect();
+    let headings = changelog_headings(&lines);
+
+    let entry_level = if headings.iter().any(|(_, level, _, _)| *level == 2) {
+        2
+    } else {
+        1
+    };
+
+    let mut unreleased = None;
+    let mut latest = None;
+
+    for (position, (_, level, text, body_start)) in headings.iter().enumerate() {
+        if *level != entry_level {
+            continue;
+        }
+        let is_unreleased = text.to_lowercase().contains("unreleased");
+        let version = heading_version(text);
+        // 문서 제목 ("# Changelog") 등 버전이 없는 제목은 건너뜀
+        if !is_unreleased && version.is_none() {
+            continue;
+        }
+
+        // 같은 수준 이상의 다음 제목 전까지가 본문
+        let end = headings[position + 1..]
+            .iter()
+            .find(|(_, next_level, _, _)| *next_level <= entry_level)
+            .map(|(next_index, _, _, _)| *next_index)
+            .unwrap_or(lines.len());
+        let notes: String = lines[(*body_start).min(end)..end]
+            .join("\n")
+            .trim()
+            .chars()
+            .take(CHANGELOG_NOTES_LIMIT)
+            .collect();
+
+        let entry = ChangelogEntry {
+            heading: text.to_string(),
+            version,
+            date: heading_date(text),
+            notes,
+        };
+        if is_unreleased {
+            if unreleased.is_none() {
+                unreleased = Some(entry);
+            }
+        } else {
+            latest = Some(entry);
+            break;
+        }
+    }
+
+    (unreleased, latest)
+}
+
+/// 프로젝트의 CHANGELOG 요약 (읽지 못하면 detection에 경고 기록)
+fn read_changelog(project_path: &Path, detection: &mut Detection) -> Option<Changelog> {
+    let path = first_existing(project_path, CHANGELOG_FILES)?;
+    let content = detection.read_optional(project_path, &path)?;
+    let (unreleased, latest) = parse_changelog(&content);
+
+    Some(Changelog {
+        file: path.file_name()?.to_string_lossy().to_string(),
+        latest,
+        unreleased,
+    })
+}
+
+/// 매니페스트 버전, 최근 태그, CHANGELOG로 릴리스 정보 구성
+///
+/// CHANGELOG를 읽지 못하면 detection에 경고 기록
+pub fn read_release_info(
+    project_path: &Path,
+    declared_versions: Vec<DeclaredVersion>,
+    detection: &mut Detection,
+) -> ReleaseInfo {
+    let tag = read_latest_tag(project_path);
+    let changelog = read_changelog(project_path, detection);
+
+    let unreleased_notes = changelog
+        .as_ref()
+        .and_then(|c| c.unreleased.as_ref())
+        .is_some_and(|entry| !entry.notes.is_empty());
+    let unreleased_commits = tag.as_ref().is_some_and(|(_, commits)| *commits > 0);
+
+    ReleaseInfo {
+        declared_versions,
+        commits_since_tag: tag.as_ref().map(|(_, commits)| *commits),
+        latest_tag: tag.map(|(name, _)| name),
+        changelog,
+        has_unreleased_changes: unreleased_commits || unreleased_notes,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_changelog_reads_keep_a_changelog_entries() {
+        let (unreleased, latest) = parse_changelog(
+            "# Changelog\n\n\
+             ## [Unreleased]\n- Pending fix\n\n\
+             ## [1.2.0] - 2024-05-01\n### Added\n- Feature\n\n\
+             ## [1.1.0] - 2024-01-01\n- Old\n",
+        );
+        let unreleased = unreleased.unwrap();
+        assert_eq!(unreleased.version, None);
+        assert_eq!(unreleased.notes, "- Pending fix");
+
+        let latest = latest.unwrap();
+        assert_eq!(latest.heading, "[1.2.0] - 2024-05-01");
+        assert_eq!(latest.version.as_deref(), Some("1.2.0"));
+        assert_eq!(latest.date.as_deref(), Some("2024-05-01"));
+        assert_eq!(latest.notes, "### Added\n- Feature");
+    }
+
+    #[test]
+    fn parse_changelog_reads_setext_headings() {
+        let (_, latest) = parse_changelog(
+            "Changelog\n=========\n\n2.0.0 (2024-02-03)\n------------------\n- Rewrite\n\n1.0.0\n-----\n- First\n",
+        );
+        let latest = latest.unwrap();
+        assert_eq!(latest.version.as_deref(), Some("2.0.0"));
+        assert_eq!(latest.notes, "- Rewrite");
+    }
+
+    #[test]
+    fn parse_changelog_falls_back_to_level_one_versions() {
+        let (_, latest) = parse_changelog("# Changelog\n\n# 0.3.0\n- Third\n\n# 0.2.0\n- Second\n");
+        assert_eq!(latest.unwrap().version.as_deref(), Some("0.3.0"));
+    }
+
+    #[test]
+    fn parse_changelog_ignores_headings_in_code_blocks() {
+        let (_, latest) = parse_changelog(
+            "# Changelog\n\n\
+             ```md\n## 9.9.9\n```\n\n\
+             ~~~~\n8.0.0\n-----\n```\n~~~~\n\n\
+             ## 1.0.0\n- Real\n",
+        );
+        let latest = latest.unwrap();
+        assert_eq!(latest.version.as_deref(), Some("1.0.0"));
+        assert_eq!(latest.notes, "- Real");
+    }
+
+    #[test]
+    fn parse_changelog_without_versions() {
+        assert!(matches!(
+            parse_changelog("# Changelog\n\nNothing yet.\n"),
+            (None, None)
+        ));
+    }
+}
@@ -1,281 +1,922 @@
+use crate::cache::{lock_cache, project_fingerprint, CachedProject, ScanCache};
+use crate::detectors::{
+    load_rule_detectors, push_unique, relative_path, DetectorRegistry, DetectorRun,
+};
+use crate::exclude::ExcludeMatcher;
+use crate::git::{
+    read_git_remotes, read_git_status, read_last_commit_time, read_submodules, read_worktree,
+    GitRemote, GitStatus, GitSubmodule, GitWorktree,
+};
+use crate::release::{read_release_info, ReleaseInfo};
+use crate::settings::{ScanOptions, ScanRoot, SymlinkPolicy};
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
 use std::fs;
-use std::path::Path;
-use toml::Value;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+use std::time::{Duration, Instant, SystemTime};
+
+/// 모노레포/워크스페이스의 하위 패키지
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SubPackage {
+    pub name: String,
+    /// 프로젝트 폴더 기준 상대 경로
+    pub path: String,
+    #[serde(rename = "type")]
+    pub package_type: String,
+    pub dependencies: Vec<String>,
+    /// 같은 프로젝트 내 다른 하위 패키지에 대한 의존성
+    pub internal_dependencies: Vec<String>,
+}
+
+/// 매니페스트에 선언된 버전
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct DeclaredVersion {
+    /// 버전을 읽은 매니페스트 파일 (예: package.json)
+    pub source: String,
+    pub version: String,
+}
+
+/// 진단 심각도
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    /// 프로젝트 정보를 읽지 못함 (깨진 매니페스트 등)
+    Error,
+    /// 일부 정보만 빠짐 (하위 패키지 매니페스트, README 등)
+    Warning,
+}
+
+/// 스캔 중 발견한 문제
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Diagnostic {
+    /// 프로젝트 폴더 기준 파일 경로 (폴더 자체의 문제면 빈 문자열)
+    pub file: String,
+    pub severity: Severity,
+    pub message: String,
+}
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct Project {
     pub name: String,
     pub path: String,
+    /// 스캔 루트 기준 상위 폴더 경로 (최상위 프로젝트는 None)
+    pub group: Option<String>,
+    /// 프로젝트를 찾은 스캔 루트 경로
+    pub root_path: String,
+    pub root_label: Option<String>,
     #[serde(rename = "type")]
     pub project_type: Vec<String>,
     pub description: String,
     pub tech_stack: Vec<String>,
-    pub last_modified: String,
-    pub git_remote: Option<String>,
+    pub targets: Vec<String>,
+    pub packages: Vec<SubPackage>,
+    pub release: ReleaseInfo,
+    /// 마지막 커밋과 마지막 파일 수정 중 최신 시각 (둘 다 모르면 None)
+    pub last_modified: Option<String>,
+    /// HEAD 커밋 시각
+    pub last_commit: Option<String>,
+    /// 작업 트리에서 가장 최근에 수정된 파일 시각
+    pub last_edited: Option<String>,
+    pub git_remotes: Vec<GitRemote>,
+    pub git_status: Option<GitStatus>,
+    pub worktree: Option<GitWorktree>,
+    pub submodules: Vec<GitSubmodule>,
     pub has_tests: bool,
     pub has_ci: bool,
+    pub default_categories: Vec<String>,
+    pub diagnostics: Vec<Diagnostic>,
+    /// 시간 또는 파일 크기 예산을 넘어 일부만 스캔한 프로젝트 (다음 스캔 때 다시 시도)
+    pub partial: bool,
+    /// 같은 프로젝트를 가리키는 다른 경로 (심볼릭 링크 등)
+    pub aliases: Vec<String>,
 }
 
 // 항상 제외할 디렉토리 (시스템 파일)
 const ALWAYS_EXCLUDED: &[&str] = &[".", "..", ".DS_Store"];
 
-/// README.md에서 설명 추출
-fn extract_description(readme_path: &Path) -> String {
-    if let Ok(content) = fs::read_to_string(readme_path) {
-        let lines: Vec<&str> = content.lines().filter(|l| !l.trim().is_empty()).collect();
-        let mut description = String::new();
-        let mut found_title = false;
-
-        for line in lines {
-            if line.starts_with('#') {
-                found_title = true;
-                let title = line.trim_start_matches('#').trim();
-                description = title.to_string();
+/// 프로젝트 하나를 스캔하는 시간 예산 (넘으면 남은 단계를 건너뛰고 부분 스캔으로 표시)
+const PROJECT_SCAN_BUDGET: Duration = Duration::from_secs(10);
+
+/// 작업 트리 수정 시각 탐색 시 최대 항목 수와 깊이
+const EDIT_SCAN_MAX_ENTRIES: usize = 20_000;
+const EDIT_SCAN_MAX_DEPTH: usize = 8;
+
+/// .gitignore가 없어도 수정 시각 탐색에서 건너뛸 폴더
+const EDIT_SCAN_SKIPPED: &[&str] = &["node_modules/", ".venv/", "venv/", "__pycache__/"];
+
+/// 작업 트리에서 가장 최근에 수정된 파일의 시각
+///
+/// .gitignore 규칙(하위 폴더의 .gitignore 포함)에 따라 무시되는 파일은 제외하며,
+/// 큰 프로젝트에서 오래 걸리지 않도록 탐색 항목 수와 깊이, 시간(deadline)을 제한
+fn last_working_tree_edit(project_path: &Path, deadline: Instant) -> Option<DateTime<Utc>> {
+    let mut matcher = ExcludeMatcher::new();
+    for pattern in EDIT_SCAN_SKIPPED {
+        matcher.add_pattern(pattern);
+    }
+
+    let mut newest: Option<SystemTime> = None;
+    let mut visited = 0;
+    let mut stack = vec![(project_path.to_path_buf(), 0)];
+
+    while let Some((dir, depth)) = stack.pop() {
+        let relative_dir = relative_path(project_path, &dir);
+        matcher.add_ignore_file(&dir.join(".gitignore"), &relative_dir);
+
+        let Ok(entries) = fs::read_dir(&dir) else {
+            continue;
+        };
+        for entry in entries.flatten() {
+            visited += 1;
+            if visited > EDIT_SCAN_MAX_ENTRIES || Instant::now() >= deadline {
+                return newest.map(DateTime::<Utc>::from);
+            }
+
+            let file_name = entry.file_name();
+            if file_name == ".git" || file_name == ".DS_Store" {
+                continue;
+            }
+            // 심볼릭 링크는 따라가지 않음
+            let Ok(file_type) = entry.file_type() else {
+                continue;
+            };
+            let path = entry.path();
+            let is_dir = file_type.is_dir();
+            if matcher.is_excluded(path.strip_prefix(project_path).unwrap_or(&path), is_dir) {
                 continue;
             }
 
-            if found_title && !line.starts_with('#') && line.len() > 10 {
-                description.push(' ');
-                description.push_str(line.trim());
-                break;
+            if is_dir {
+                if depth < EDIT_SCAN_MAX_DEPTH {
+                    stack.push((path, depth + 1));
+                }
+            } else if file_type.is_file() {
+                if let Ok(modified) = entry.metadata().and_then(|m| m.modified()) {
+                    if newest.map_or(true, |current| modified > current) {
+                        newest = Some(modified);
+                    }
+                }
             }
         }
+    }
 
-        if description.is_empty() {
-            return "설명 없음".to_string();
-        }
+    newest.map(DateTime::<Utc>::from)
+}
 
-        description.chars().take(150).collect()
-    } else {
-        "설명 없음".to_string()
-    }
+/// 감지기 하나의 누적 실행 시간
+#[derive(Debug, Serialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct DetectorMetrics {
+    pub name: String,
+    /// 실행 횟수 (스캔한 프로젝트 수)
+    pub runs: usize,
+    /// Detection을 반환한 횟수
+    pub matches: usize,
+    pub total_ms: f64,
+    pub max_ms: f64,
+    /// 가장 오래 걸린 프로젝트 경로
+    pub slowest_path: Option<String>,
 }
 
-/// Git remote URL 추출
-fn extract_git_remote(project_path: &Path) -> Option<String> {
-    let git_config_path = project_path.join(".git").join("config");
+/// 스캔 한 번의 소요 시간 통계
+#[derive(Debug, Serialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct ScanMetrics {
+    pub project_count: usize,
+    /// 변경이 없어 캐시된 결과를 사용한 프로젝트 수
+    pub cached: usize,
+    /// 사용한 작업 스레드 수
+    pub workers: usize,
+    /// 폴더 탐색부터 마지막 프로젝트까지 걸린 시간
+    pub elapsed_ms: f64,
+    /// 폴더 탐색에 걸린 시간
+    pub discovery_ms: f64,
+    /// Git 정보 및 최근 수정 시각 읽기에 걸린 시간 (모든 스레드 합계)
+    pub git_ms: f64,
+    /// 감지기별 통계 (누적 시간 내림차순)
+    pub detectors: Vec<DetectorMetrics>,
+}
 
-    if let Ok(content) = fs::read_to_string(git_config_path) {
-        for line in content.lines() {
-            if line.trim().starts_with("url") {
-                if let Some(url) = line.split('=').nth(1) {
-                    return Some(url.trim().to_string());
+impl ScanMetrics {
+    /// 프로젝트 하나의 감지기 실행 시간 반영
+    fn record_detectors(&mut self, project_path: &Path, runs: &[DetectorRun]) {
+        for run in runs {
+            let index = match self.detectors.iter().position(|d| d.name == run.name) {
+                Some(index) => index,
+                None => {
+                    self.detectors.push(DetectorMetrics {
+                        name: run.name.to_string(),
+                        ..DetectorMetrics::default()
+                    });
+                    self.detectors.len() - 1
                 }
+            };
+            let metrics = &mut self.detectors[index];
+            let elapsed = duration_ms(run.elapsed);
+            metrics.runs += 1;
+            metrics.total_ms += elapsed;
+            if run.matched {
+                metrics.matches += 1;
+            }
+            if elapsed > metrics.max_ms || metrics.slowest_path.is_none() {
+                metrics.max_ms = elapsed;
+                metrics.slowest_path = Some(project_path.to_string_lossy().to_string());
             }
         }
     }
 
-    None
-}
-
-/// 마지막 수정 날짜 가져오기
-fn get_last_modified_date(project_path: &Path) -> String {
-    // .git/logs/HEAD에서 마지막 커밋 날짜 추출
-    let git_log_path = project_path.join(".git").join("logs").join("HEAD");
-
-    if let Ok(content) = fs::read_to_string(git_log_path) {
-        if let Some(last_line) = content.lines().last() {
-            // 로그 형식: ... > timestamp ...
-            if let Some(timestamp_str) = last_line.split('>').nth(1) {
-                if let Some(timestamp) = timestamp_str.trim().split_whitespace().next() {
-                    if let Ok(ts) = timestamp.parse::<i64>() {
-                        use chrono::{DateTime, Utc};
-                        let dt = DateTime::<Utc>::from_timestamp(ts, 0);
-                        if let Some(datetime) = dt {
-                            return datetime.to_rfc3339();
-                        }
+    /// 다른 작업 스레드의 통계 합산
+    fn merge(&mut self, other: ScanMetrics) {
+        self.project_count += other.project_count;
+        self.cached += other.cached;
+        self.git_ms += other.git_ms;
+        for detector in other.detectors {
+            match self.detectors.iter_mut().find(|d| d.name == detector.name) {
+                Some(existing) => {
+                    existing.runs += detector.runs;
+                    existing.matches += detector.matches;
+                    existing.total_ms += detector.total_ms;
+                    if detector.max_ms > existing.max_ms {
+                        existing.max_ms = detector.max_ms;
+                        existing.slowest_path = detector.slowest_path;
                     }
                 }
+                None => self.detectors.push(detector),
             }
         }
     }
+}
 
-    // 디렉토리 수정 시간 사용
-    if let Ok(metadata) = fs::metadata(project_path) {
-        if let Ok(modified) = metadata.modified() {
-            use chrono::{DateTime, Utc};
-            let datetime: DateTime<Utc> = modified.into();
-            return datetime.to_rfc3339();
-        }
-    }
+fn duration_ms(duration: Duration) -> f64 {
+    duration.as_secs_f64() * 1000.0
+}
 
-    use chrono::Utc;
-    Utc::now().to_rfc3339()
+/// 스캔 결과와 소요 시간 통계
+#[derive(Debug, Clone)]
+pub struct ScanReport {
+    pub projects: Vec<Project>,
+    pub metrics: ScanMetrics,
+    /// 중간에 취소되어 일부 프로젝트만 스캔한 경우 true
+    pub cancelled: bool,
+    /// 읽지 못한 스캔 루트와 감지 규칙 파일의 오류 (나머지는 정상 스캔)
+    pub errors: Vec<String>,
 }
 
-/// 단일 프로젝트 스캔
-fn scan_project(project_path: &Path) -> Project {
+/// 스캔 진행 상황 수신 및 취소 확인
+///
+/// 여러 작업 스레드에서 동시에 호출되므로 Sync 필요
+pub trait ScanProgress: Sync {
+    /// 폴더 탐색이 끝나고 스캔할 프로젝트 수가 정해졌을 때
+    fn started(&self, _total: usize) {}
+
+    /// 프로젝트 하나를 스캔했을 때 (index는 최종 결과 순서, 호출 순서는 보장하지 않음)
+    fn scanned(&self, _index: usize, _project: &Project, _cached: bool) {}
+
+    /// true를 반환하면 남은 프로젝트를 스캔하지 않고 중단
+    fn is_cancelled(&self) -> bool {
+        false
+    }
+}
+
+/// 진행 상황이 필요 없는 스캔
+pub struct NoProgress;
+
+impl ScanProgress for NoProgress {}
+
+fn scan_project(
+    project_path: &Path,
+    group: Option<String>,
+    root: &ScanRoot,
+    registry: &DetectorRegistry,
+    metrics: &mut ScanMetrics,
+) -> Project {
     let project_name = project_path
         .file_name()
         .unwrap_or_default()
         .to_string_lossy()
         .to_string();
 
-    let mut project = Project {
-        name: project_name.clone(),
-        path: project_path.to_string_lossy().to_string(),
-        project_type: Vec::new(),
-        description: "설명 없음".to_string(),
-        tech_stack: Vec::new(),
-        last_modified: get_last_modified_date(project_path),
-        git_remote: None,
-        has_tests: false,
-        has_ci: false,
+    let started = Instant::now();
+    let deadline = started + PROJECT_SCAN_BUDGET;
+    let (mut detection, runs) = registry.detect(project_path, deadline);
+    metrics.record_detectors(project_path, &runs);
+    if let Err(e) = fs::read_dir(project_path) {
+        detection.diagnostics.insert(
+            0,
+            Diagnostic {
+                file: String::new(),
+                severity: Severity::Error,
+                message: format!("Failed to read project folder: {}", e),
+            },
+        );
+    }
+    for tag in &root.default_tags {
+        push_unique(&mut detection.categories, tag.clone());
+    }
+
+    // 예산을 넘으면 남은 Git 정보와 최근 수정 시각은 읽지 않음
+    let git_started = Instant::now();
+    let mut skipped: Vec<&str> = Vec::new();
+    let mut within_budget = |step| {
+        let within = Instant::now() < deadline;
+        if !within {
+            skipped.push(step);
+        }
+        within
+    };
+    let last_commit = within_budget("last commit")
+        .then(|| read_last_commit_time(project_path))
+        .flatten();
+    let last_edited = within_budget("recent edits")
+        .then(|| last_working_tree_edit(project_path, deadline))
+        .flatten();
+    let versions = std::mem::take(&mut detection.versions);
+    let release = if within_budget("release") {
+        read_release_info(project_path, versions, &mut detection)
+    } else {
+        ReleaseInfo {
+            declared_versions: versions,
+            ..ReleaseInfo::default()
+        }
+    };
+    let git_remotes = if within_budget("remotes") {
+        read_git_remotes(project_path)
+    } else {
+        Vec::new()
+    };
+    let git_status = within_budget("git status")
+        .then(|| read_git_status(project_path))
+        .flatten();
+    let worktree = within_budget("worktree")
+        .then(|| read_worktree(project_path))
+        .flatten();
+    let submodules = if within_budget("submodules") {
+        read_submodules(project_path)
+    } else {
+        Vec::new()
     };
+    metrics.git_ms += duration_ms(git_started.elapsed());
+    metrics.project_count += 1;
+
+    // git 명령이 시간 초과로 중단된 경우도 포함
+    let elapsed = started.elapsed();
+    if elapsed > PROJECT_SCAN_BUDGET {
+        detection.partial = true;
+        let mut message = format!(
+            "Scan took {:.1}s (budget {}s)",
+            elapsed.as_secs_f64(),
+            PROJECT_SCAN_BUDGET.as_secs()
+        );
+        if skipped.is_empty() {
+            message.push_str(", Git information may be incomplete");
+        } else {
+            message.push_str(&format!(", skipped: {}", skipped.join(", ")));
+        }
+        detection.warn(String::new(), message);
+    }
 
-    // package.json 확인 (Node.js)
-    let package_json_path = project_path.join("package.json");
-    if package_json_path.exists() {
-        if let Ok(content) = fs::read_to_string(&package_json_path) {
-            if let Ok(pkg) = serde_json::from_str::<serde_json::Value>(&content) {
-                project.project_type.push("nodejs".to_string());
+    Project {
+        name: project_name,
+        path: project_path.to_string_lossy().to_string(),
+        group,
+        root_path: root.path.clone(),
+        root_label: root.label.clone().filter(|l| !l.trim().is_empty()),
+        project_type: detection.project_types,
+        description: detection
+            .description
+            .unwrap_or_else(|| "설명 없음".to_string()),
+        tech_stack: detection.tech_stack,
+        targets: detection.targets,
+        packages: detection.packages,
+        release,
+        last_modified: last_commit.max(last_edited).map(|t| t.to_rfc3339()),
+        last_commit: last_commit.map(|t| t.to_rfc3339()),
+        last_edited: last_edited.map(|t| t.to_rfc3339()),
+        git_remotes,
+        git_status,
+        worktree,
+        submodules,
+        has_tests: detection.has_tests,
+        has_ci: detection.has_ci,
+        default_categories: detection.categories,
+        diagnostics: detection.diagnostics,
+        partial: detection.partial,
+        aliases: Vec::new(),
+    }
+}
 
-                if let Some(desc) = pkg.get("description").and_then(|v| v.as_str()) {
-                    project.description = desc.to_string();
-                }
+/// 탐색 중 찾은 프로젝트 폴더
+struct FoundDir {
+    path: PathBuf,
+    group: Option<String>,
+    /// 심볼릭 링크를 거쳐 찾은 경로 (같은 실제 폴더를 다른 경로로 찾으면 별칭이 됨)
+    via_link: bool,
+}
 
-                // 기술 스택 추출
-                let mut deps = Vec::new();
-                if let Some(dependencies) = pkg.get("dependencies").and_then(|v| v.as_object()) {
-                    deps.extend(dependencies.keys().cloned());
-                }
-                if let Some(dev_deps) = pkg.get("devDependencies").and_then(|v| v.as_object()) {
-                    deps.extend(dev_deps.keys().cloned());
-                }
-                project.tech_stack = deps.iter().take(10).map(|s| s.to_string()).collect();
+/// 심볼릭 링크 정책 follow에서 나중에 내려갈 링크 폴더
+struct DeferredLink {
+    path: PathBuf,
+    group: Option<String>,
+    depth: usize,
+}
+
+/// 스캔 루트 하나의 폴더 탐색 상태
+struct DirWalker<'a> {
+    workspace: &'a Path,
+    max_depth: usize,
+    symlink_policy: SymlinkPolicy,
+    excluder: ExcludeMatcher,
+    registry: &'a DetectorRegistry,
+    /// 이미 내려간 폴더의 실제 경로 (심볼릭 링크 순환 방지)
+    visited: HashSet<PathBuf>,
+    /// 실제 폴더를 모두 탐색한 뒤 내려갈 링크 (실제 경로가 먼저 선택되도록)
+    deferred: Vec<DeferredLink>,
+    found: Vec<FoundDir>,
+}
+
+impl DirWalker<'_> {
+    /// 스캔 루트 전체 탐색
+    fn walk_root(&mut self) {
+        self.walk(self.workspace, 1, false);
+        while let Some(link) = self.deferred.pop() {
+            self.visit(link.path, link.group, link.depth, true);
+        }
+    }
+
+    /// 폴더 바로 아래 항목 탐색
+    fn walk(&mut self, dir: &Path, depth: usize, via_link: bool) {
+        let Ok(entries) = fs::read_dir(dir) else {
+            return;
+        };
 
-                // 테스트 확인
-                if let Some(scripts) = pkg.get("scripts").and_then(|v| v.as_object()) {
-                    if scripts.contains_key("test") || scripts.contains_key("test:unit") {
-                        project.has_tests = true;
+        let group = Some(relative_path(self.workspace, dir)).filter(|g| !g.is_empty());
+
+        for entry in entries.flatten() {
+            let file_name = entry.file_name().to_string_lossy().to_string();
+
+            // 시스템 파일 제외
+            if ALWAYS_EXCLUDED.contains(&file_name.as_str()) {
+                continue;
+            }
+
+            let path = entry.path();
+            if !path.is_dir() {
+                continue;
+            }
+
+            // 설정 및 .code-tare-ignore에서 지정한 패턴 제외
+            let relative = path.strip_prefix(self.workspace).unwrap_or(&path);
+            if self.excluder.is_excluded(relative, true) {
+                continue;
+            }
+
+            let is_link = entry.file_type().is_ok_and(|t| t.is_symlink());
+            if !is_link {
+                self.visit(path, group.clone(), depth, via_link);
+                continue;
+            }
+
+            match self.symlink_policy {
+                SymlinkPolicy::Skip => {}
+                SymlinkPolicy::Follow => self.deferred.push(DeferredLink {
+                    path,
+                    group: group.clone(),
+                    depth,
+                }),
+                // 링크된 그룹 폴더로는 내려가지 않고, 프로젝트를 가리키는 링크만 별칭으로 수집
+                SymlinkPolicy::Alias => {
+                    if self.registry.is_project_dir(&path) || !self.can_descend(&file_name, depth) {
+                        self.found.push(FoundDir {
+                            path,
+                            group: group.clone(),
+                            via_link: true,
+                        });
                     }
                 }
             }
         }
     }
 
-    // pyproject.toml 확인 (Python Poetry)
-    let pyproject_path = project_path.join("pyproject.toml");
-    if pyproject_path.exists() {
-        if let Ok(content) = fs::read_to_string(&pyproject_path) {
-            if let Ok(config) = content.parse::<Value>() {
-                project.project_type.push("python-poetry".to_string());
-
-                if let Some(desc) = config
-                    .get("tool")
-                    .and_then(|t| t.get("poetry"))
-                    .and_then(|p| p.get("description"))
-                    .and_then(|d| d.as_str())
-                {
-                    project.description = desc.to_string();
-                }
+    /// 숨김 폴더는 그룹 폴더로 보지 않음
+    fn can_descend(&self, file_name: &str, depth: usize) -> bool {
+        depth < self.max_depth && !file_name.starts_with('.')
+    }
 
-                // 의존성 추출
-                if let Some(deps) = config
-                    .get("tool")
-                    .and_then(|t| t.get("poetry"))
-                    .and_then(|p| p.get("dependencies"))
-                    .and_then(|d| d.as_table())
-                {
-                    let dep_list: Vec<String> = deps
-                        .keys()
-                        .filter(|k| k.as_str() != "python")
-                        .take(10)
-                        .map(|k| k.to_string())
-                        .collect();
-                    project.tech_stack.extend(dep_list);
-                }
+    /// 폴더 하나를 프로젝트로 수집하거나 그룹 폴더로 보고 내려감
+    ///
+    /// 프로젝트 표식이 있는 폴더는 프로젝트로 수집하고 더 내려가지 않음.
+    /// 표식이 없는 폴더는 최대 깊이까지 내려가며, 하위에서 프로젝트를 찾지 못하면
+    /// 그 폴더 자체를 프로젝트로 취급 (기존 한 단계 스캔과 같은 동작)
+    fn visit(&mut self, path: PathBuf, group: Option<String>, depth: usize, via_link: bool) {
+        let file_name = path
+            .file_name()
+            .map(|n| n.to_string_lossy().to_string())
+            .unwrap_or_default();
+
+        if self.registry.is_project_dir(&path) || !self.can_descend(&file_name, depth) {
+            self.found.push(FoundDir {
+                path,
+                group,
+                via_link,
+            });
+            return;
+        }
+
+        // 이미 탐색한 실제 폴더로 돌아오는 링크는 건너뜀
+        if let Ok(canonical) = path.canonicalize() {
+            if !self.visited.insert(canonical) {
+                return;
             }
         }
+
+        let before = self.found.len();
+        self.walk(&path, depth + 1, via_link);
+        if self.found.len() == before {
+            self.found.push(FoundDir {
+                path,
+                group,
+                via_link,
+            });
+        }
     }
+}
 
-    // requirements.txt 확인 (Python pip)
-    let requirements_path = project_path.join("requirements.txt");
-    if requirements_path.exists() {
-        if let Ok(content) = fs::read_to_string(&requirements_path) {
-            let deps: Vec<String> = content
-                .lines()
-                .map(|l| l.trim())
-                .filter(|l| !l.is_empty() && !l.starts_with('#'))
-                .filter_map(|l| {
-                    l.split(&['=', '<', '>'][..])
-                        .next()
-                        .map(|s| s.to_string())
-                })
-                .take(10)
-                .collect();
+/// 내장 감지기와 사용자 정의 규칙이 등록된 레지스트리
+///
+/// 규칙 파일을 읽지 못하면 내장 감지기만 등록하고 오류를 함께 반환
+pub(crate) fn build_registry() -> (DetectorRegistry, Option<String>) {
+    let mut registry = DetectorRegistry::with_builtin();
+    let rules_error = match load_rule_detectors() {
+        Ok(detectors) => {
+            for detector in detectors {
+                registry.register(detector);
+            }
+            None
+        }
+        Err(e) => {
+            println!("{}", e);
+            Some(e)
+        }
+    };
+    println!("Detectors: {}", registry.detector_names().join(", "));
+    (registry, rules_error)
+}
+
+/// 스캔 루트 하나의 프로젝트 폴더와 그룹 경로
+fn find_root_project_dirs(
+    root: &ScanRoot,
+    options: &ScanOptions,
+    registry: &DetectorRegistry,
+) -> Result<Vec<FoundDir>, String> {
+    let workspace = Path::new(&root.path);
+    if !workspace.exists() {
+        return Err(format!("Workspace path does not exist: {}", root.path));
+    }
+    fs::read_dir(workspace)
+        .map_err(|e| format!("Failed to read workspace directory {}: {}", root.path, e))?;
+
+    // 전역 제외 목록 + 루트별 제외 목록 + .code-tare-ignore
+    let mut patterns = options.excluded_folders.clone();
+    patterns.extend(root.excluded_folders.iter().cloned());
+
+    let mut walker = DirWalker {
+        workspace,
+        max_depth: options.max_depth.max(1),
+        symlink_policy: options.symlink_policy,
+        excluder: ExcludeMatcher::for_root(workspace, &patterns),
+        registry,
+        visited: workspace.canonicalize().into_iter().collect(),
+        deferred: Vec::new(),
+        found: Vec::new(),
+    };
+    walker.walk_root();
+    Ok(walker.found)
+}
+
+/// 모든 스캔 루트의 프로젝트 폴더 목록 (감지기 실행 없이 위치만, 읽을 수 없는 루트는 건너뜀)
+pub fn find_project_dirs(roots: &[ScanRoot], options: &ScanOptions) -> Vec<PathBuf> {
+    let (registry, _) = build_registry();
+    discover_scan_jobs(roots, options, &registry)
+        .map(|(jobs, _)| jobs.into_iter().map(|job| job.path).collect())
+        .unwrap_or_default()
+}
+
+/// 동시에 스캔할 최대 작업 스레드 수
+const MAX_SCAN_WORKERS: usize = 8;
+
+/// 프로젝트 하나의 스캔 작업
+pub(crate) struct ScanJob<'a> {
+    pub path: PathBuf,
+    pub group: Option<String>,
+    pub root: &'a ScanRoot,
+    /// 같은 실제 폴더를 가리키는 다른 경로 (심볼릭 링크, 겹치는 스캔 루트)
+    pub aliases: Vec<String>,
+}
+
+impl ScanJob<'_> {
+    fn add_alias(&mut self, path: &Path) {
+        if path != self.path {
+            push_unique(&mut self.aliases, path.to_string_lossy().to_string());
+        }
+    }
+}
 
-            project.project_type.push("python-pip".to_string());
-            project.tech_stack.extend(deps);
+/// 모든 스캔 루트에서 스캔할 프로젝트 폴더 탐색
+///
+/// 같은 실제 폴더는 먼저 찾은 경로로 한 번만 포함하고 나머지 경로는 별칭으로 기록하며,
+/// 읽을 수 없는 루트의 오류 목록을 함께 반환.
+/// 스캔 루트가 없거나 모든 루트를 읽을 수 없는 경우에만 오류를 반환
+pub(crate) fn discover_scan_jobs<'a>(
+    roots: &'a [ScanRoot],
+    options: &ScanOptions,
+    registry: &DetectorRegistry,
+) -> Result<(Vec<ScanJob<'a>>, Vec<String>), String> {
+    if roots.is_empty() {
+        return Err("No scan folder configured".to_string());
+    }
+
+    let mut jobs: Vec<ScanJob> = Vec::new();
+    // 실제 경로 → jobs 위치
+    let mut job_index: HashMap<PathBuf, usize> = HashMap::new();
+    let mut links: Vec<(FoundDir, &ScanRoot)> = Vec::new();
+    let mut errors = Vec::new();
+
+    for root in roots {
+        match find_root_project_dirs(root, options, registry) {
+            Ok(found) => {
+                for dir in found {
+                    if dir.via_link {
+                        links.push((dir, root));
+                        continue;
+                    }
+                    let key = dir.path.canonicalize().unwrap_or_else(|_| dir.path.clone());
+                    match job_index.get(&key) {
+                        Some(&index) => jobs[index].add_alias(&dir.path),
+                        None => {
+                            job_index.insert(key, jobs.len());
+                            jobs.push(ScanJob {
+                                path: dir.path,
+                                group: dir.group,
+                                root,
+                                aliases: Vec::new(),
+                            });
+                        }
+                    }
+                }
+            }
+            Err(e) => errors.push(e),
         }
     }
 
-    // README.md 확인
-    let readme_path = project_path.join("README.md");
-    if readme_path.exists() && project.description == "설명 없음" {
-        project.description = extract_description(&readme_path);
+    // 링크를 거쳐 찾은 폴더는 실제 폴더를 모두 찾은 뒤 처리 (처음 찾은 실제 폴더면 실제 경로로 추가)
+    for (link, root) in links {
+        let Ok(target) = link.path.canonicalize() else {
+            continue;
+        };
+        match job_index.get(&target) {
+            Some(&index) => jobs[index].add_alias(&link.path),
+            None => {
+                job_index.insert(target.clone(), jobs.len());
+                let mut job = ScanJob {
+                    path: target,
+                    group: link.group,
+                    root,
+                    aliases: Vec::new(),
+                };
+                job.add_alias(&link.path);
+                jobs.push(job);
+            }
+        }
     }
 
-    // Git 정보
-    project.git_remote = extract_git_remote(project_path);
-
-    // CI 파일 확인
-    let ci_paths = vec![
-        project_path.join(".github").join("workflows"),
-        project_path.join(".gitlab-ci.yml"),
-        project_path.join(".travis.yml"),
-    ];
-    project.has_ci = ci_paths.iter().any(|p| p.exists());
-
-    // 테스트 폴더 확인
-    if !project.has_tests {
-        let test_dirs = vec!["test", "tests", "__tests__", "spec"];
-        project.has_tests = test_dirs
-            .iter()
-            .any(|dir| project_path.join(dir).exists());
+    if errors.len() == roots.len() {
+        return Err(errors.join("\n"));
     }
+    Ok((jobs, errors))
+}
 
+/// 프로젝트 하나를 다시 스캔하고 캐시 항목 갱신
+pub(crate) fn rescan_project(job: &ScanJob, registry: &DetectorRegistry) -> Project {
+    let mut metrics = ScanMetrics::default();
+    let entry = scan_job(job, registry, None, &mut metrics);
+    let project = entry.project.clone();
+
+    let _cache_lock = lock_cache();
+    let mut cache = ScanCache::load(&registry.detector_names());
+    cache.projects.insert(project.path.clone(), entry);
+    if let Err(e) = cache.save() {
+        println!("{}", e);
+    }
     project
 }
 
-/// 모든 프로젝트 스캔
-pub fn scan_all_projects(workspace_path: &str, excluded_folders: &[String]) -> Result<Vec<Project>, String> {
-    println!("Scanning projects in: {}", workspace_path);
+/// 사라진 프로젝트를 캐시에서 제거하고 마지막으로 스캔한 결과 반환
+pub(crate) fn forget_project(project_path: &Path, registry: &DetectorRegistry) -> Option<Project> {
+    let _cache_lock = lock_cache();
+    let mut cache = ScanCache::load(&registry.detector_names());
+    let removed = cache
+        .projects
+        .remove(project_path.to_string_lossy().as_ref())?;
+    if let Err(e) = cache.save() {
+        println!("{}", e);
+    }
+    Some(removed.project)
+}
 
-    let workspace = Path::new(workspace_path);
-    if !workspace.exists() {
-        return Err(format!("Workspace path does not exist: {}", workspace_path));
+/// 캐시가 유효하면 캐시된 결과, 아니면 새로 스캔한 결과와 지문
+fn scan_job(
+    job: &ScanJob,
+    registry: &DetectorRegistry,
+    cache: Option<&ScanCache>,
+    metrics: &mut ScanMetrics,
+) -> CachedProject {
+    if let Some(mut cached) = cache.and_then(|c| c.lookup(&job.path, &job.group, job.root)) {
+        metrics.project_count += 1;
+        metrics.cached += 1;
+        // 별칭은 탐색 결과에 따라 바뀌므로 지문과 관계없이 갱신
+        cached.project.aliases = job.aliases.clone();
+        return cached;
     }
 
-    let mut projects = Vec::new();
+    let mut project = scan_project(&job.path, job.group.clone(), job.root, registry, metrics);
+    project.aliases = job.aliases.clone();
+    CachedProject {
+        // 부분 스캔 결과는 지문 없이 저장해 다음 스캔 때 다시 시도
+        fingerprint: if project.partial {
+            Vec::new()
+        } else {
+            project_fingerprint(&job.path, &project.packages)
+        },
+        default_tags: job.root.default_tags.clone(),
+        project,
+    }
+}
 
-    let entries = fs::read_dir(workspace)
-        .map_err(|e| format!("Failed to read workspace directory: {}", e))?;
+/// 작업 목록을 제한된 수의 스레드로 나눠 스캔 (결과는 작업 목록 순서 유지)
+fn scan_jobs(
+    jobs: &[ScanJob],
+    registry: &DetectorRegistry,
+    cache: Option<&ScanCache>,
+    progress: &dyn ScanProgress,
+) -> (Vec<CachedProject>, ScanMetrics) {
+    // 대부분의 시간이 git 프로세스 대기이므로 코어 수보다 조금 많이 사용
+    let workers = thread::available_parallelism()
+        .map(|n| n.get() * 2)
+        .unwrap_or(2)
+        .min(MAX_SCAN_WORKERS)
+        .min(jobs.len())
+        .max(1);
+    let next_job = AtomicUsize::new(0);
+
+    let results: Vec<(Vec<(usize, CachedProject)>, ScanMetrics)> = thread::scope(|scope| {
+        let handles: Vec<_> = (0..workers)
+            .map(|_| {
+                scope.spawn(|| {
+                    let mut scanned = Vec::new();
+                    let mut metrics = ScanMetrics::default();
+                    while !progress.is_cancelled() {
+                        let index = next_job.fetch_add(1, Ordering::Relaxed);
+                        let Some(job) = jobs.get(index) else {
+                            break;
+                        };
+                        let cached_before = metrics.cached;
+                        let entry = scan_job(job, registry, cache, &mut metrics);
+                        progress.scanned(index, &entry.project, metrics.cached > cached_before);
+                        scanned.push((index, entry));
+                    }
+                    (scanned, metrics)
+                })
+            })
+            .collect();
+
+        handles
+            .into_iter()
+            .filter_map(|handle| match handle.join() {
+                Ok(result) => Some(result),
+                Err(_) => {
+                    println!("Scan worker panicked, some projects were skipped");
+                    None
+                }
+            })
+            .collect()
+    });
 
-    for entry in entries {
-        if let Ok(entry) = entry {
-            let file_name = entry.file_name().to_string_lossy().to_string();
+    let mut metrics = ScanMetrics {
+        workers,
+        ..ScanMetrics::default()
+    };
+    let mut indexed = Vec::with_capacity(jobs.len());
+    for (scanned, worker_metrics) in results {
+        indexed.extend(scanned);
+        metrics.merge(worker_metrics);
+    }
+    indexed.sort_by_key(|(index, _)| *index);
 
-            // 시스템 파일 제외
-            if ALWAYS_EXCLUDED.contains(&file_name.as_str()) {
-                continue;
-            }
+    (
+        indexed.into_iter().map(|(_, project)| project).collect(),
+        metrics,
+    )
+}
 
-            // 설정에서 지정한 폴더 제외
-            if excluded_folders.iter().any(|excluded| excluded == &file_name) {
-                continue;
-            }
+/// 모든 스캔 루트의 프로젝트 스캔
+///
+/// 일부 루트가 없거나 읽을 수 없어도 나머지 결과는 반환하며,
+/// 모든 루트가 실패한 경우에만 오류를 반환.
+/// 지문이 바뀌지 않은 프로젝트는 데이터 폴더의 캐시를 사용하며, `force_full`이면 모두 다시 스캔.
+/// `progress`가 취소를 알리면 그때까지 스캔한 프로젝트만 반환
+pub fn scan_all_projects(
+    roots: &[ScanRoot],
+    options: &ScanOptions,
+    force_full: bool,
+    progress: &dyn ScanProgress,
+) -> Result<ScanReport, String> {
+    let (registry, rules_error) = build_registry();
+    let started = Instant::now();
+    let detector_names = registry.detector_names();
+    let cache = if force_full {
+        None
+    } else {
+        Some(ScanCache::load(&detector_names))
+    };
 
-            let path = entry.path();
-            if path.is_dir() {
-                let project = scan_project(&path);
-                projects.push(project);
-            }
-        }
+    for root in roots {
+        println!("Scanning projects in: {}", root.path);
+    }
+    let (jobs, mut errors) = discover_scan_jobs(roots, options, &registry)?;
+    for error in &errors {
+        println!("Failed to scan: {}", error);
+    }
+    errors.extend(rules_error);
+
+    let discovery_ms = duration_ms(started.elapsed());
+    progress.started(jobs.len());
+    let (scanned, mut metrics) = scan_jobs(&jobs, &registry, cache.as_ref(), progress);
+    let cancelled = progress.is_cancelled() && scanned.len() < jobs.len();
+
+    // 기존 캐시를 이번 결과로 갱신
+    // - 찾았지만 결과가 없는 프로젝트 (취소, 작업 스레드 패닉)는 기존 항목 유지
+    // - 찾지 못한 프로젝트는 사라진 것이 확인된 경우만 제거
+    //   (폴더가 없거나, 설정에서 빠졌거나, 정상적으로 읽은 스캔 루트에서 더 이상 찾지 못함)
+    let cache_lock = lock_cache();
+    let mut new_cache = ScanCache::load(&detector_names);
+    let discovered: HashSet<&Path> = jobs.iter().map(|job| job.path.as_path()).collect();
+    let unreadable_roots: HashSet<&str> = roots
+        .iter()
+        .filter(|root| fs::read_dir(&root.path).is_err())
+        .map(|root| root.path.as_str())
+        .collect();
+    new_cache.projects.retain(|path, entry| {
+        discovered.contains(Path::new(path))
+            || (Path::new(path).exists()
+                && unreadable_roots.contains(entry.project.root_path.as_str()))
+    });
+    let mut projects = Vec::with_capacity(scanned.len());
+    for entry in scanned {
+        projects.push(entry.project.clone());
+        new_cache.projects.insert(entry.project.path.clone(), entry);
+    }
+    if let Err(e) = new_cache.save() {
+        println!("{}", e);
+    }
+    drop(cache_lock);
+
+    metrics.discovery_ms = discovery_ms;
+    metrics.elapsed_ms = duration_ms(started.elapsed());
+    metrics
+        .detectors
+        .sort_by(|a, b| b.total_ms.total_cmp(&a.total_ms));
+
+    if cancelled {
+        println!(
+            "Scan cancelled after {} of {} projects",
+            projects.len(),
+            jobs.len()
+        );
+    }
+    println!(
+        "Found {} projects in {:.0}ms ({} cached, {} workers)",
+        projects.len(),
+        metrics.elapsed_ms,
+        metrics.cached,
+        metrics.workers
+    );
+    for detector in metrics.detectors.iter().take(3) {
+        println!(
+            "  {}: {:.1}ms total, {:.1}ms max",
+            detector.name, detector.total_ms, detector.max_ms
+        );
     }
 
-    println!("Found {} projects", projects.len());
-    Ok(projects)
+    Ok(ScanReport {
+        projects,
+        metrics,
+        cancelled,
+        errors,
+    })
 }
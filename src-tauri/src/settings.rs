@@ -2,15 +2,82 @@ use serde::{Deserialize, Serialize};
 use std::fs;
 use std::path::PathBuf;
 
+/// 프로젝트를 찾을 최상위 폴더
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ScanRoot {
+    pub path: String,
+    #[serde(default)]
+    pub label: Option<String>,
+    /// 이 루트에만 적용할 제외 폴더 (전역 제외 목록에 추가됨)
+    #[serde(default)]
+    pub excluded_folders: Vec<String>,
+    /// 이 루트의 프로젝트에 기본으로 붙일 구분 태그
+    #[serde(default)]
+    pub default_tags: Vec<String>,
+}
+
+impl ScanRoot {
+    fn from_path(path: &str) -> Self {
+        ScanRoot {
+            path: path.to_string(),
+            label: None,
+            excluded_folders: Vec::new(),
+            default_tags: Vec::new(),
+        }
+    }
+}
+
+/// 스캔 루트 안의 심볼릭 링크 폴더 처리 방식
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum SymlinkPolicy {
+    /// 일반 폴더처럼 내려가며 탐색 (같은 실제 폴더는 한 번만)
+    Follow,
+    /// 링크된 폴더는 무시
+    Skip,
+    /// 링크된 폴더는 내려가지 않고, 가리키는 실제 폴더의 프로젝트에 별칭으로 표시
+    #[default]
+    Alias,
+}
+
+/// 스캔 루트 외의 프로젝트 탐색 옵션
+#[derive(Debug, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ScanOptions {
+    pub excluded_folders: Vec<String>,
+    pub max_depth: usize,
+    pub symlink_policy: SymlinkPolicy,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct AppSettings {
+    /// 이전 버전의 단일 스캔 경로 (scan_roots가 비어 있을 때만 사용)
+    #[serde(default)]
     pub scan_path: String,
+    #[serde(default)]
+    pub scan_roots: Vec<ScanRoot>,
     pub terminal_app: String,
     pub editor_command: String,
     pub excluded_folders: Vec<String>,
     pub hide_archived: bool,
     pub hide_hidden_projects: bool,
+    /// 프로젝트를 찾을 최대 폴더 깊이 (1이면 스캔 경로 바로 아래만)
+    #[serde(default = "default_max_scan_depth")]
+    pub max_scan_depth: usize,
+    /// 웹으로 열 원격 저장소 이름 (없으면 origin)
+    #[serde(default)]
+    pub preferred_remote: Option<String>,
+    #[serde(default)]
+    pub symlink_policy: SymlinkPolicy,
+    /// 프로젝트 README 언어 (ko, ja 등, 없으면 시스템 언어)
+    #[serde(default)]
+    pub preferred_locale: Option<String>,
+}
+
+fn default_max_scan_depth() -> usize {
+    3
 }
 
 impl Default for AppSettings {
@@ -22,6 +89,7 @@ impl Default for AppSettings {
             .to_string();
 
         AppSettings {
+            scan_roots: vec![ScanRoot::from_path(&default_path)],
             scan_path: default_path,
             terminal_app: "Warp".to_string(),
             editor_command: "code".to_string(),
@@ -40,11 +108,16 @@ impl Default for AppSettings {
             ],
             hide_archived: true,
             hide_hidden_projects: true,
+            max_scan_depth: default_max_scan_depth(),
+            preferred_remote: None,
+            symlink_policy: SymlinkPolicy::default(),
+            preferred_locale: None,
         }
     }
 }
 
-fn get_settings_file() -> PathBuf {
+/// 앱 데이터 디렉토리 (settings.json 및 사용자 설정 파일 위치)
+pub fn get_data_dir() -> PathBuf {
     // macOS Application Support 디렉토리 사용 (쓰기 가능)
     let app_data_dir = dirs::data_local_dir()
         .unwrap_or_else(|| PathBuf::from("."))
@@ -55,14 +128,44 @@ fn get_settings_file() -> PathBuf {
         fs::create_dir_all(&app_data_dir).ok();
     }
 
-    app_data_dir.join("settings.json")
+    app_data_dir
+}
+
+impl AppSettings {
+    /// 실제로 스캔할 루트 목록 (scan_roots가 비어 있으면 scan_path 하나)
+    pub fn effective_scan_roots(&self) -> Vec<ScanRoot> {
+        if !self.scan_roots.is_empty() {
+            return self.scan_roots.clone();
+        }
+        if self.scan_path.is_empty() {
+            return Vec::new();
+        }
+        vec![ScanRoot::from_path(&self.scan_path)]
+    }
+
+    /// 프로젝트 탐색 옵션
+    pub fn scan_options(&self) -> ScanOptions {
+        ScanOptions {
+            excluded_folders: self.excluded_folders.clone(),
+            max_depth: self.max_scan_depth,
+            symlink_policy: self.symlink_policy,
+        }
+    }
+}
+
+pub(crate) fn get_settings_file() -> PathBuf {
+    get_data_dir().join("settings.json")
 }
 
 pub fn load_settings() -> AppSettings {
     let settings_path = get_settings_file();
 
     if let Ok(content) = fs::read_to_string(&settings_path) {
-        if let Ok(settings) = serde_json::from_str(&content) {
+        if let Ok(mut settings) = serde_json::from_str::<AppSettings>(&content) {
+            // 단일 scan_path만 있는 이전 설정은 스캔 루트 목록으로 옮김
+            if settings.scan_roots.is_empty() {
+                settings.scan_roots = settings.effective_scan_roots();
+            }
             return settings;
         }
     }
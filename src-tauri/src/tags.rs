@@ -1,7 +1,8 @@
+use crate::scanner::Project;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
@@ -313,11 +314,18 @@ pub fn update_tag_color(tag: &str, color: &str) -> Result<TagColors, String> {
     Ok(colors)
 }
 
-/// 특정 프로젝트의 태그 가져오기
-pub fn get_project_tags(project_name: &str) -> ProjectTags {
+/// 이전 버전에서 태그 키로 쓰던 폴더 이름
+fn legacy_tag_key(project_path: &str) -> Option<String> {
+    Path::new(project_path)
+        .file_name()
+        .map(|name| name.to_string_lossy().to_string())
+}
+
+/// 특정 프로젝트의 태그 가져오기 (경로 기준)
+pub fn get_project_tags(project_path: &str) -> ProjectTags {
     let all_tags = load_project_tags();
 
-    all_tags.get(project_name).cloned().unwrap_or(ProjectTags {
+    all_tags.get(project_path).cloned().unwrap_or(ProjectTags {
         custom_title: None,
         progress: "계획중".to_string(),
         categories: Vec::new(),
@@ -327,8 +335,8 @@ pub fn get_project_tags(project_name: &str) -> ProjectTags {
     })
 }
 
-/// 특정 프로젝트의 태그 저장
-pub fn set_project_tags(project_name: &str, mut tags: ProjectTags) -> Result<(), String> {
+/// 특정 프로젝트의 태그 저장 (경로 기준)
+pub fn set_project_tags(project_path: &str, mut tags: ProjectTags) -> Result<(), String> {
     // customTitle 유효성 검사
     if let Some(ref title) = tags.custom_title {
         let trimmed = title.trim();
@@ -339,9 +347,86 @@ pub fn set_project_tags(project_name: &str, mut tags: ProjectTags) -> Result<(),
         }
     }
 
+    // 폴더 이름 기준 태그는 같은 이름의 다른 프로젝트가 아직 옮기지 못했을 수 있으므로 지우지 않음 (migrate_legacy_tags에서 정리)
     let mut all_tags = load_project_tags();
-    all_tags.insert(project_name.to_string(), tags);
+    all_tags.insert(project_path.to_string(), tags);
     save_project_tags(&all_tags)?;
 
     Ok(())
 }
+
+/// 폴더 이름 기준 태그 이전 결과
+#[derive(Debug, Default)]
+pub struct LegacyTagMigration {
+    /// 폴더 이름 기준 태그를 경로 기준으로 받은 프로젝트 경로
+    pub migrated: Vec<String>,
+    /// 같은 이름의 프로젝트가 여러 개라 옮기지 못한 태그 안내
+    pub ambiguous: Vec<String>,
+}
+
+/// 경로나 별칭 경로 기준 태그가 있는지
+fn has_path_tags(all_tags: &HashMap<String, ProjectTags>, project: &Project) -> bool {
+    all_tags.contains_key(&project.path)
+        || project.aliases.iter().any(|alias| all_tags.contains_key(alias))
+}
+
+/// 이전 버전의 폴더 이름 기준 태그를 경로 기준으로 옮김
+///
+/// 같은 이름의 프로젝트가 여러 루트에 있으면 어느 프로젝트의 태그인지 알 수 없으므로 옮기지 않고 안내만 반환하며,
+/// 그 프로젝트들이 모두 태그를 따로 받으면 이름 키를 정리함.
+/// 전체 스캔이 끝난 뒤 호출해야 하며, 스캔한 프로젝트 중 해당 이름이 없으면 (연결 해제된 루트 등) 그대로 둠
+pub fn migrate_legacy_tags(projects: &[Project]) -> Result<LegacyTagMigration, String> {
+    let mut all_tags = load_project_tags();
+    let mut result = LegacyTagMigration::default();
+    if all_tags.keys().all(|key| Path::new(key).is_absolute()) {
+        return Ok(result);
+    }
+
+    // 이름 키가 남아 있는 폴더 이름별 프로젝트
+    let mut by_name: BTreeMap<String, Vec<&Project>> = BTreeMap::new();
+    for project in projects {
+        let Some(key) = legacy_tag_key(&project.path) else {
+            continue;
+        };
+        if all_tags.contains_key(&key) {
+            by_name.entry(key).or_default().push(project);
+        }
+    }
+
+    let mut cleaned = 0;
+    for (key, same_name) in by_name {
+        let untagged: Vec<&Project> = same_name
+            .iter()
+            .copied()
+            .filter(|project| !has_path_tags(&all_tags, project))
+            .collect();
+        match untagged.as_slice() {
+            [] => {}
+            [project] if same_name.len() == 1 => {
+                if let Some(tags) = all_tags.get(&key).cloned() {
+                    all_tags.insert(project.path.clone(), tags);
+                    result.migrated.push(project.path.clone());
+                }
+            }
+            _ => {
+                let paths: Vec<&str> = same_name.iter().map(|p| p.path.as_str()).collect();
+                result.ambiguous.push(format!(
+                    "Tags saved for folder name \"{}\" were not migrated: {} projects share this name ({}). Set their tags individually.",
+                    key,
+                    same_name.len(),
+                    paths.join(", ")
+                ));
+                continue;
+            }
+        }
+        all_tags.remove(&key);
+        cleaned += 1;
+    }
+    if cleaned == 0 {
+        return Ok(result);
+    }
+
+    println!("Migrated {} folder-name tag entries to project paths", cleaned);
+    save_project_tags(&all_tags)?;
+    Ok(result)
+}
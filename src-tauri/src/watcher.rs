@@ -0,0 +1,572 @@
+use crate::cache::{fingerprint_dirs, lock_cache, project_fingerprint, FileStamp, ScanCache};
+use crate::commands::{project_with_tags, ProjectWithTags};
+use crate::detectors::{get_detection_rules_file, DetectorRegistry};
+use crate::exclude::ExcludeMatcher;
+use crate::recent::load_last_opened;
+use crate::scanner::{
+    build_registry, discover_scan_jobs, forget_project, rescan_project, Project, ScanJob,
+    SubPackage,
+};
+use crate::settings::{get_data_dir, get_settings_file, load_settings, ScanOptions, ScanRoot};
+use crate::tags::load_project_tags;
+use notify::event::EventKind;
+use notify::{Event, RecommendedWatcher, RecursiveMode, Watcher};
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::thread;
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, Emitter};
+
+/// 변경이 멈춘 뒤 다시 스캔하기까지 기다리는 시간 (저장 중이거나 git 작업 중인 프로젝트 제외)
+const DEBOUNCE: Duration = Duration::from_secs(2);
+
+/// 없거나 읽을 수 없는 스캔 루트를 다시 확인하는 간격
+const ROOT_RETRY_INTERVAL: Duration = Duration::from_secs(30);
+
+/// 처리할 변경이 없을 때 이벤트를 기다리는 최대 시간
+const IDLE_WAIT: Duration = Duration::from_secs(60);
+
+/// 감시할 최대 프로젝트 수 (넘으면 감시를 멈추고 새로고침으로만 갱신)
+const MAX_WATCHED_PROJECTS: usize = 2000;
+
+/// 설정의 제외 목록이 없어도 감시하지 않는 무거운 폴더 (하위 패키지 위치로 감지되어도 제외)
+const WATCH_SKIPPED: &[&str] = &[
+    "node_modules/",
+    "target/",
+    ".venv/",
+    "venv/",
+    "__pycache__/",
+];
+
+pub const PROJECT_ADDED_EVENT: &str = "project-added";
+pub const PROJECT_REMOVED_EVENT: &str = "project-removed";
+pub const PROJECT_UPDATED_EVENT: &str = "project-updated";
+
+/// 다시 스캔할 때 필요한 프로젝트 탐색 결과 (ScanJob은 루트를 빌려 쓰므로 루트 위치로 저장)
+struct WatchedProject {
+    group: Option<String>,
+    root: usize,
+    aliases: Vec<String>,
+    /// 마지막으로 스캔한 결과 (하위 패키지 지문과 삭제 이벤트용, 캐시에 없으면 None)
+    last_scan: Option<Project>,
+}
+
+/// 스캔 루트 감시 상태
+struct WatchState {
+    watcher: RecommendedWatcher,
+    settings_file: PathBuf,
+    rules_file: PathBuf,
+    /// 감시 기준이 된 설정 (스캔 루트, 탐색 옵션, 감지 규칙 파일)
+    config: String,
+    roots: Vec<ScanRoot>,
+    options: ScanOptions,
+    /// 설정이나 감지 규칙 파일이 바뀔 때 다시 만드는 감지기 레지스트리
+    registry: DetectorRegistry,
+    /// 파일 시스템 이벤트를 받고 있는 폴더 (하위 폴더 제외) → 그 폴더의 변경으로 다시 스캔할 프로젝트
+    ///
+    /// 스캔 루트와 그룹 폴더는 프로젝트 없이 등록되며, 변경이 있으면 프로젝트 폴더 목록을 다시 탐색
+    watched_dirs: HashMap<PathBuf, Vec<PathBuf>>,
+    /// 이미 출력한 스캔 루트 오류 (같은 오류는 한 번만 출력)
+    root_errors: HashSet<String>,
+    /// 감시를 시작하지 못해 이미 오류를 출력한 폴더
+    watch_errors: HashSet<PathBuf>,
+    last_root_check: Instant,
+    /// 프론트엔드가 알고 있는 프로젝트
+    projects: HashMap<PathBuf, WatchedProject>,
+    /// 프로젝트 경로와 별칭 경로 → 프로젝트 경로
+    paths: HashMap<PathBuf, PathBuf>,
+    /// 마지막으로 스캔한 프로젝트별 지문 (스캔 중 git이 쓴 파일로 다시 스캔하지 않도록)
+    fingerprints: HashMap<PathBuf, Vec<FileStamp>>,
+    /// 변경이 감지된 프로젝트와 마지막 변경 감지 시각
+    pending: HashMap<PathBuf, Instant>,
+    /// 프로젝트가 추가/삭제되었을 수 있는 변경의 마지막 감지 시각
+    rediscover_at: Option<Instant>,
+    /// 설정 또는 감지 규칙 파일의 마지막 변경 감지 시각
+    config_changed_at: Option<Instant>,
+    /// 프로젝트 수 제한을 넘어 감시를 멈춘 상태
+    over_limit: bool,
+}
+
+/// 감시 기준 설정 (스캔 루트, 탐색 옵션, 감지 규칙 파일 지문)
+fn watch_config() -> (String, Vec<ScanRoot>, ScanOptions) {
+    let settings = load_settings();
+    let roots = settings.effective_scan_roots();
+    let options = settings.scan_options();
+    let rules = FileStamp::read(&get_detection_rules_file());
+    let config = serde_json::to_string(&(&roots, &options, rules)).unwrap_or_default();
+    (config, roots, options)
+}
+
+/// 스캔 루트 감시 스레드 시작
+///
+/// 지문에 포함되는 폴더(프로젝트와 하위 패키지 폴더 바로 아래, Git 메타데이터)만 재귀 없이 감시해
+/// 변경이 멈춘 프로젝트만 다시 스캔 (node_modules 등 내부 폴더는 등록하지 않음).
+/// 스캔 루트와 그룹 폴더도 재귀 없이 감시해 그 안의 변경이 있을 때만 프로젝트 폴더 목록을 다시 탐색
+pub fn start_project_watcher(app: AppHandle) {
+    thread::spawn(move || {
+        let (sender, receiver) = mpsc::channel();
+        let watcher = match notify::recommended_watcher(sender) {
+            Ok(watcher) => watcher,
+            Err(e) => {
+                println!("Failed to start project watcher: {}", e);
+                return;
+            }
+        };
+        let mut state = WatchState::new(watcher);
+
+        loop {
+            match receiver.recv_timeout(state.next_wakeup()) {
+                Ok(result) => {
+                    state.record(result);
+                    while let Ok(result) = receiver.try_recv() {
+                        state.record(result);
+                    }
+                }
+                Err(RecvTimeoutError::Timeout) => {}
+                Err(RecvTimeoutError::Disconnected) => return,
+            }
+            state.process(&app);
+        }
+    });
+}
+
+impl WatchState {
+    fn new(mut watcher: RecommendedWatcher) -> Self {
+        // 설정/감지 규칙 파일 변경 감지
+        if let Err(e) = watcher.watch(&get_data_dir(), RecursiveMode::NonRecursive) {
+            println!("Failed to watch settings folder: {}", e);
+        }
+
+        let (config, roots, options) = watch_config();
+        let mut state = WatchState {
+            watcher,
+            settings_file: get_settings_file(),
+            rules_file: get_detection_rules_file(),
+            config,
+            roots,
+            options,
+            registry: build_registry().0,
+            watched_dirs: HashMap::new(),
+            root_errors: HashSet::new(),
+            watch_errors: HashSet::new(),
+            last_root_check: Instant::now(),
+            projects: HashMap::new(),
+            paths: HashMap::new(),
+            fingerprints: HashMap::new(),
+            pending: HashMap::new(),
+            rediscover_at: None,
+            config_changed_at: None,
+            over_limit: false,
+        };
+        state.rediscover(None);
+        state
+    }
+
+    /// 다음으로 처리할 변경까지 남은 시간
+    fn next_wakeup(&self) -> Duration {
+        let now = Instant::now();
+        let mut wakeup = IDLE_WAIT;
+        let changes = self
+            .pending
+            .values()
+            .chain(&self.rediscover_at)
+            .chain(&self.config_changed_at);
+        for changed_at in changes {
+            wakeup = wakeup.min((*changed_at + DEBOUNCE).saturating_duration_since(now));
+        }
+        if self.has_unwatched_roots() {
+            wakeup = wakeup
+                .min((self.last_root_check + ROOT_RETRY_INTERVAL).saturating_duration_since(now));
+        }
+        wakeup
+    }
+
+    fn has_unwatched_roots(&self) -> bool {
+        !self.over_limit
+            && self
+                .roots
+                .iter()
+                .any(|root| !self.watched_dirs.contains_key(Path::new(&root.path)))
+    }
+
+    /// 파일 시스템 이벤트 기록 (처리는 변경이 멈춘 뒤)
+    fn record(&mut self, result: notify::Result<Event>) {
+        let event = match result {
+            Ok(event) => event,
+            Err(e) => {
+                println!("Project watcher error: {}", e);
+                return;
+            }
+        };
+        // 파일 열기/읽기는 스캔 자체도 발생시키므로 무시
+        if matches!(event.kind, EventKind::Access(_)) {
+            return;
+        }
+
+        let now = Instant::now();
+        // 이벤트가 너무 많아 일부를 놓친 경우 전체 다시 확인
+        if event.need_rescan() {
+            self.rediscover_at = Some(now);
+            for path in self.projects.keys() {
+                self.pending.insert(path.clone(), now);
+            }
+            return;
+        }
+
+        for path in &event.paths {
+            if *path == self.settings_file || *path == self.rules_file {
+                self.config_changed_at = Some(now);
+                continue;
+            }
+            if self.over_limit {
+                continue;
+            }
+
+            // 프로젝트 폴더 자체가 이동/삭제됨
+            if let Some(project) = self.paths.get(path) {
+                self.rediscover_at = Some(now);
+                self.pending.insert(project.clone(), now);
+                continue;
+            }
+            // 감시 중인 프로젝트 폴더 (하위 패키지, Git 메타데이터 포함) 바로 아래 변경
+            let owners = path
+                .parent()
+                .and_then(|parent| self.watched_dirs.get(parent))
+                .filter(|owners| !owners.is_empty());
+            if let Some(owners) = owners {
+                for project in owners {
+                    self.pending.insert(project.clone(), now);
+                }
+                continue;
+            }
+            // 스캔 루트나 그룹 폴더의 변경은 프로젝트가 생기거나 없어졌을 수 있음
+            if self.roots.iter().any(|root| path.starts_with(&root.path)) {
+                self.rediscover_at = Some(now);
+            }
+        }
+    }
+
+    /// 변경이 멈춘 항목 처리
+    fn process(&mut self, app: &AppHandle) {
+        let now = Instant::now();
+        let is_due = |changed_at: &Option<Instant>| {
+            changed_at.is_some_and(|changed_at| now.duration_since(changed_at) >= DEBOUNCE)
+        };
+
+        if is_due(&self.config_changed_at) {
+            self.config_changed_at = None;
+            let (config, roots, options) = watch_config();
+            // 스캔 루트나 감지 규칙이 바뀌면 추가/삭제된 프로젝트를 알리고 남은 프로젝트도 모두 다시 스캔
+            if config != self.config {
+                self.config = config;
+                self.roots = roots;
+                self.options = options;
+                self.registry = build_registry().0;
+                self.over_limit = false;
+                self.root_errors.clear();
+                self.watch_errors.clear();
+                self.fingerprints.clear();
+                self.rediscover_at = None;
+                self.rediscover(Some(app));
+                let known: Vec<PathBuf> = self.projects.keys().cloned().collect();
+                for path in known {
+                    self.pending
+                        .insert(path, now.checked_sub(DEBOUNCE).unwrap_or(now));
+                }
+            }
+        }
+
+        // 없던 스캔 루트가 생겼는지 주기적으로 확인
+        let retry_roots = self.has_unwatched_roots()
+            && now.duration_since(self.last_root_check) >= ROOT_RETRY_INTERVAL;
+        if retry_roots || is_due(&self.rediscover_at) {
+            self.rediscover_at = None;
+            self.rediscover(Some(app));
+        }
+
+        let ready: Vec<PathBuf> = self
+            .pending
+            .iter()
+            .filter(|(_, changed_at)| now.duration_since(**changed_at) >= DEBOUNCE)
+            .map(|(path, _)| path.clone())
+            .collect();
+        if ready.is_empty() {
+            return;
+        }
+
+        let settings = load_settings();
+        let project_tags = load_project_tags();
+        let last_opened = load_last_opened();
+        for path in ready {
+            self.pending.remove(&path);
+            if !self.projects.contains_key(&path) {
+                continue;
+            }
+            let fingerprint = project_fingerprint(&path, self.packages(&path));
+            if self.fingerprints.get(&path) == Some(&fingerprint) {
+                continue;
+            }
+            if let Some(project) = self.rescan(&path) {
+                let payload = project_with_tags(project, &project_tags, &last_opened, &settings);
+                emit_project(app, PROJECT_UPDATED_EVENT, &payload);
+            }
+        }
+    }
+
+    /// 마지막으로 스캔한 프로젝트의 하위 패키지
+    fn packages(&self, path: &Path) -> &[SubPackage] {
+        self.projects
+            .get(path)
+            .and_then(|watched| watched.last_scan.as_ref())
+            .map(|project| project.packages.as_slice())
+            .unwrap_or_default()
+    }
+
+    /// 프로젝트 하나를 다시 스캔하고 스캔 후 지문 기록 (하위 패키지가 바뀌면 감시 폴더도 갱신)
+    fn rescan(&mut self, path: &Path) -> Option<Project> {
+        let watched = self.projects.get(path)?;
+        let job = ScanJob {
+            path: path.to_path_buf(),
+            group: watched.group.clone(),
+            root: self.roots.get(watched.root)?,
+            aliases: watched.aliases.clone(),
+        };
+        let project = rescan_project(&job, &self.registry);
+        self.fingerprints.insert(
+            path.to_path_buf(),
+            project_fingerprint(path, &project.packages),
+        );
+
+        let package_paths = |project: &Project| -> Vec<String> {
+            project.packages.iter().map(|p| p.path.clone()).collect()
+        };
+        let previous = self
+            .projects
+            .get_mut(path)?
+            .last_scan
+            .replace(project.clone());
+        if previous.as_ref().map(package_paths) != Some(package_paths(&project)) {
+            self.sync_watches();
+        }
+        Some(project)
+    }
+
+    /// 프로젝트 폴더 목록을 다시 탐색하고 감시 폴더 갱신
+    ///
+    /// app이 있으면 새로 생기거나 없어진 프로젝트를 이벤트로 전송,
+    /// 없으면 (시작 시) 캐시된 스캔 결과를 기준으로만 삼음
+    fn rediscover(&mut self, app: Option<&AppHandle>) {
+        self.last_root_check = Instant::now();
+        if self.over_limit {
+            return;
+        }
+
+        let (jobs, errors) = discover_scan_jobs(&self.roots, &self.options, &self.registry)
+            .unwrap_or_else(|e| (Vec::new(), e.lines().map(str::to_string).collect()));
+        // 없거나 읽을 수 없는 루트는 다시 생길 때까지 한 번만 출력
+        for error in &errors {
+            if self.root_errors.insert(error.clone()) {
+                println!("Watching paused for scan root: {}", error);
+            }
+        }
+        self.root_errors.retain(|error| errors.contains(error));
+
+        if jobs.len() > MAX_WATCHED_PROJECTS {
+            println!(
+                "Watching disabled: {} projects exceed the limit of {}",
+                jobs.len(),
+                MAX_WATCHED_PROJECTS
+            );
+            self.over_limit = true;
+            for dir in self.watched_dirs.keys() {
+                self.watcher.unwatch(dir).ok();
+            }
+            self.watched_dirs.clear();
+            self.projects.clear();
+            self.paths.clear();
+            self.fingerprints.clear();
+            self.pending.clear();
+            return;
+        }
+
+        let mut previous = std::mem::take(&mut self.projects);
+        let mut projects = HashMap::with_capacity(jobs.len());
+        let mut paths = HashMap::with_capacity(jobs.len());
+        for job in &jobs {
+            for alias in &job.aliases {
+                paths.insert(PathBuf::from(alias), job.path.clone());
+            }
+            paths.insert(job.path.clone(), job.path.clone());
+            let root = self
+                .roots
+                .iter()
+                .position(|root| std::ptr::eq(root, job.root))
+                .unwrap_or_default();
+            let last_scan = previous
+                .get_mut(&job.path)
+                .and_then(|watched| watched.last_scan.take());
+            projects.insert(
+                job.path.clone(),
+                WatchedProject {
+                    group: job.group.clone(),
+                    root,
+                    aliases: job.aliases.clone(),
+                    last_scan,
+                },
+            );
+        }
+        self.projects = projects;
+        self.paths = paths;
+
+        let Some(app) = app else {
+            let cache = {
+                let _cache_lock = lock_cache();
+                ScanCache::load(&self.registry.detector_names())
+            };
+            for (path, watched) in self.projects.iter_mut() {
+                watched.last_scan = cache
+                    .projects
+                    .get(path.to_string_lossy().as_ref())
+                    .map(|cached| cached.project.clone());
+            }
+            self.fingerprints = self
+                .projects
+                .keys()
+                .map(|path| (path.clone(), project_fingerprint(path, self.packages(path))))
+                .collect();
+            self.pending.clear();
+            self.sync_watches();
+            return;
+        };
+
+        let added: Vec<PathBuf> = self
+            .projects
+            .keys()
+            .filter(|path| !previous.contains_key(*path))
+            .cloned()
+            .collect();
+        let removed: Vec<(PathBuf, WatchedProject)> = previous
+            .into_iter()
+            .filter(|(path, _)| !self.projects.contains_key(path))
+            .collect();
+        self.sync_watches();
+        if added.is_empty() && removed.is_empty() {
+            return;
+        }
+
+        let settings = load_settings();
+        let project_tags = load_project_tags();
+        let last_opened = load_last_opened();
+        let to_payload =
+            |project| project_with_tags(project, &project_tags, &last_opened, &settings);
+
+        for path in added {
+            self.pending.remove(&path);
+            if let Some(project) = self.rescan(&path) {
+                emit_project(app, PROJECT_ADDED_EVENT, &to_payload(project));
+            }
+        }
+        for (path, watched) in removed {
+            self.pending.remove(&path);
+            self.fingerprints.remove(&path);
+            // 감지 규칙이 바뀌어 캐시가 무효화된 경우에도 마지막 스캔 결과로 알림
+            if let Some(project) = forget_project(&path, &self.registry).or(watched.last_scan) {
+                emit_project(app, PROJECT_REMOVED_EVENT, &to_payload(project));
+            }
+        }
+    }
+
+    /// 감시 폴더 갱신 (모두 재귀 없이 등록)
+    ///
+    /// 스캔 루트와 루트~프로젝트 사이의 그룹 폴더, 프로젝트별 지문 폴더를 감시하며,
+    /// 설정의 제외 목록이나 무거운 폴더(node_modules, target 등)에 해당하는 하위 패키지 폴더는 등록하지 않음
+    fn sync_watches(&mut self) {
+        let matchers: Vec<ExcludeMatcher> = self
+            .roots
+            .iter()
+            .map(|root| {
+                let mut patterns = self.options.excluded_folders.clone();
+                patterns.extend(root.excluded_folders.iter().cloned());
+                patterns.extend(WATCH_SKIPPED.iter().map(|p| p.to_string()));
+                ExcludeMatcher::for_root(Path::new(&root.path), &patterns)
+            })
+            .collect();
+
+        let mut wanted: HashMap<PathBuf, Vec<PathBuf>> = HashMap::new();
+        for root in &self.roots {
+            let path = PathBuf::from(&root.path);
+            if path.is_dir() {
+                wanted.entry(path).or_default();
+            }
+        }
+        for (path, watched) in &self.projects {
+            let (Some(root), Some(matcher)) =
+                (self.roots.get(watched.root), matchers.get(watched.root))
+            else {
+                continue;
+            };
+            let root_path = Path::new(&root.path);
+            for group_dir in path
+                .ancestors()
+                .skip(1)
+                .take_while(|dir| *dir != root_path && dir.starts_with(root_path))
+            {
+                wanted.entry(group_dir.to_path_buf()).or_default();
+            }
+
+            let packages = watched
+                .last_scan
+                .as_ref()
+                .map(|project| project.packages.as_slice())
+                .unwrap_or_default();
+            for dir in fingerprint_dirs(path, packages) {
+                let excluded = dir != *path
+                    && dir.starts_with(path)
+                    && dir
+                        .strip_prefix(root_path)
+                        .is_ok_and(|relative| matcher.is_excluded(relative, true));
+                if !excluded {
+                    wanted.entry(dir).or_default().push(path.clone());
+                }
+            }
+        }
+
+        let stale: Vec<PathBuf> = self
+            .watched_dirs
+            .keys()
+            .filter(|dir| !wanted.contains_key(*dir))
+            .cloned()
+            .collect();
+        for dir in stale {
+            self.watcher.unwatch(&dir).ok();
+            self.watched_dirs.remove(&dir);
+        }
+        self.watch_errors.retain(|dir| wanted.contains_key(dir));
+
+        for (dir, owners) in wanted {
+            if let Some(current) = self.watched_dirs.get_mut(&dir) {
+                *current = owners;
+                continue;
+            }
+            match self.watcher.watch(&dir, RecursiveMode::NonRecursive) {
+                Ok(()) => {
+                    self.watch_errors.remove(&dir);
+                    self.watched_dirs.insert(dir, owners);
+                }
+                Err(e) => {
+                    if self.watch_errors.insert(dir.clone()) {
+                        println!("Failed to watch {}: {}", dir.display(), e);
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn emit_project(app: &AppHandle, event: &str, project: &ProjectWithTags) {
+    println!("{}: {}", event, project.path);
+    if let Err(e) = app.emit(event, project) {
+        println!("Failed to emit {}: {}", event, e);
+    }
+}